@@ -0,0 +1,157 @@
+//! Zero-copy archiving of BoolVec with rkyv, only available with the `rkyv` feature.
+//!
+//! A BoolVec is archived as the number of values followed by the packed bytes storing them,
+//! most significant bit first whatever the bit order of the BoolVec, just like BoolSlice::bytes() yields them.
+//! Since those are exactly the bytes a BoolSlice views, an ArchivedBoolVec has every method of BoolSlice
+//! reading values, working straight on the archive without deserializing it:
+//! ```rust
+//! use bool_vec::{boolvec, ArchivedBoolVec, BoolVec};
+//! use rkyv::rancor::Error;
+//!
+//! let mut bv = boolvec![false; 1000];
+//! bv.set(3, true);
+//! bv.set(999, true);
+//!
+//! let bytes = rkyv::to_bytes::<Error>(&bv).unwrap();
+//!
+//! // The archive is validated once, then the values are read in place
+//! let archived = rkyv::access::<ArchivedBoolVec, Error>(&bytes).unwrap();
+//!
+//! assert_eq!(archived.len(), 1000);
+//! assert_eq!(archived.count_ones(), 2);
+//! assert_eq!(archived.get(999), Some(true));
+//!
+//! let deserialized = rkyv::deserialize::<BoolVec, Error>(archived).unwrap();
+//!
+//! assert_eq!(deserialized, bv);
+//! ```
+
+use std::io;
+use std::ops::RangeBounds;
+
+use rkyv::rancor::Fallible;
+use rkyv::ser::{Allocator as ArchiveAllocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{munge::munge, Archive, Archived, Deserialize, Place, Portable, Serialize};
+
+use crate::slice::forward_read_methods;
+use crate::{Allocator, BitOrder, BoolSequence, BoolSlice, BoolSliceIndex, BoolVec, BoolVecError};
+use crate::{BoolVecIter, Windows, WindowsU64};
+use crate::{Bytes, Chunks, ChunksU64, Differences, DisplayWith, FormatOptions, Runs};
+
+/// An archived BoolVec, whose values can be read just like with a BoolSlice without deserializing it.
+/// Check the `archive` module docs for more infos
+#[derive(Portable)]
+#[rkyv(crate = rkyv)]
+#[derive(rkyv::bytecheck::CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedBoolVec {
+    /// The number of values, archived as a u64 so that huge BoolVecs fit regardless of the pointer width
+    len: Archived<u64>,
+
+    /// The packed bytes storing the values, just enough to hold them
+    bytes: ArchivedVec<u8>,
+}
+
+impl ArchivedBoolVec {
+    /// Returns a BoolSlice viewing the archived values
+    /// ```rust
+    /// use bool_vec::{boolvec, ArchivedBoolVec};
+    /// use rkyv::rancor::Error;
+    ///
+    /// let bytes = rkyv::to_bytes::<Error>(&boolvec![true, false, true]).unwrap();
+    /// let archived = rkyv::access::<ArchivedBoolVec, Error>(&bytes).unwrap();
+    ///
+    /// assert_eq!(archived.as_slice(), boolvec![true, false, true]);
+    /// ```
+    pub fn as_slice(&self) -> BoolSlice<'_> {
+        BoolSlice::from_bytes(self.bytes.as_slice(), self.len.to_native() as usize)
+            .expect("archived bytes should hold every archived value")
+    }
+
+    /// Returns the number of archived values
+    /// ```rust
+    /// use bool_vec::{boolvec, ArchivedBoolVec};
+    /// use rkyv::rancor::Error;
+    ///
+    /// let bytes = rkyv::to_bytes::<Error>(&boolvec![true; 10]).unwrap();
+    /// let archived = rkyv::access::<ArchivedBoolVec, Error>(&bytes).unwrap();
+    ///
+    /// assert_eq!(archived.len(), 10);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns true if no value was archived
+    /// ```rust
+    /// use bool_vec::{ArchivedBoolVec, BoolVec};
+    /// use rkyv::rancor::Error;
+    ///
+    /// let bytes = rkyv::to_bytes::<Error>(&BoolVec::new()).unwrap();
+    /// let archived = rkyv::access::<ArchivedBoolVec, Error>(&bytes).unwrap();
+    ///
+    /// assert!(archived.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    forward_read_methods!();
+}
+
+impl<A: Allocator> Archive for BoolVec<A> {
+    type Archived = ArchivedBoolVec;
+
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: VecResolver, out: Place<ArchivedBoolVec>) {
+        munge!(let ArchivedBoolVec { len, bytes } = out);
+
+        (self.len() as u64).resolve((), len);
+        ArchivedVec::resolve_from_len(self.as_bytes().len(), resolver, bytes);
+    }
+}
+
+impl<A: Allocator, S: Fallible + ArchiveAllocator + Writer + ?Sized> Serialize<S> for BoolVec<A> {
+    fn serialize(&self, serializer: &mut S) -> Result<VecResolver, S::Error> {
+        ArchivedVec::serialize_from_slice(&self.msb0_bytes(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<BoolVec, D> for ArchivedBoolVec {
+    fn deserialize(&self, _: &mut D) -> Result<BoolVec, D::Error> {
+        Ok(self.as_slice().to_owned())
+    }
+}
+
+mod verify {
+    use rkyv::bytecheck::Verify;
+    use rkyv::rancor::{Fallible, Source};
+
+    use super::ArchivedBoolVec;
+    use crate::BoolVecError;
+
+    // SAFETY: as_slice() only requires the archived bytes to hold every archived value,
+    // which is exactly what's checked here
+    unsafe impl<C> Verify<C> for ArchivedBoolVec
+    where
+        C: Fallible + ?Sized,
+        C::Error: Source,
+    {
+        fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+            let len = self.bytes.len().saturating_mul(8);
+            let bit_len = self.len.to_native();
+
+            if usize::try_from(bit_len).map_or(true, |bit_len| bit_len > len) {
+                return Err(Source::new(BoolVecError::NotEnoughValues {
+                    bit_len: bit_len as usize,
+                    len,
+                }));
+            }
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Mutable access to a single value of a BoolSliceMut.
+
+use std::fmt::Debug;
+use std::ops::{Deref, DerefMut};
+
+use crate::BoolSliceMut;
+
+/// Proxy giving mutable access to a single value of a BoolSliceMut or a BoolVec,
+/// since a `&mut bool` can't point inside packed bits. Check BoolSliceMut::get_mut() docs for more infos.
+///
+/// The proxy holds a copy of the value, which can be changed with BitMut::set()
+/// or by dereferencing it, just like a `&mut bool`.
+/// The value is written back into the BoolSliceMut when the proxy is dropped:
+/// ```rust
+/// use bool_vec::boolvec;
+///
+/// let mut bv = boolvec![true, false, true];
+///
+/// if let Some(mut b) = bv.get_mut(1) {
+///     *b = !*b;
+/// }
+///
+/// assert_eq!(bv, boolvec![true, true, true]);
+/// ```
+pub struct BitMut<'a> {
+    slice: BoolSliceMut<'a>,
+    index: usize,
+
+    /// Value of the proxy, written back on drop
+    value: bool,
+
+    /// Value when the proxy was created, so that unchanged values are not written back
+    original: bool,
+}
+
+impl<'a> BitMut<'a> {
+    /// Builds a proxy for the value at `index`, which must be lower than BoolSliceMut.len()
+    pub(crate) fn new(slice: BoolSliceMut<'a>, index: usize, value: bool) -> Self {
+        Self {
+            slice,
+            index,
+            value,
+            original: value,
+        }
+    }
+
+    /// Returns the current value of the proxy
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false];
+    ///
+    /// assert_eq!(bv.get_mut(0).unwrap().get(), true);
+    /// ```
+    pub fn get(&self) -> bool {
+        self.value
+    }
+
+    /// Changes the value of the proxy, which is written back when the proxy is dropped
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false];
+    ///
+    /// bv.get_mut(1).unwrap().set(true);
+    ///
+    /// assert_eq!(bv, boolvec![true, true]);
+    /// ```
+    pub fn set(&mut self, value: bool) {
+        self.value = value;
+    }
+
+    /// Negates the value of the proxy, which is written back when the proxy is dropped
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false];
+    ///
+    /// let mut b = bv.get_mut(0).unwrap();
+    /// b.toggle();
+    ///
+    /// assert_eq!(*b, false);
+    ///
+    /// drop(b);
+    ///
+    /// assert_eq!(bv, boolvec![false, false]);
+    /// ```
+    pub fn toggle(&mut self) {
+        self.value = !self.value;
+    }
+}
+
+/// Former name of BitMut, kept so that existing code keeps compiling
+pub type BitRef<'a> = BitMut<'a>;
+
+impl Deref for BitMut<'_> {
+    type Target = bool;
+
+    fn deref(&self) -> &bool {
+        &self.value
+    }
+}
+
+impl DerefMut for BitMut<'_> {
+    fn deref_mut(&mut self) -> &mut bool {
+        &mut self.value
+    }
+}
+
+impl Debug for BitMut<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.value, f)
+    }
+}
+
+impl Drop for BitMut<'_> {
+    fn drop(&mut self) {
+        if self.value != self.original {
+            let _ = self.slice.set(self.index, self.value);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Order in which values are packed into the bits of each byte of a BoolVec or BoolSlice.
+
+/// Order in which values are packed into the bits of each byte.
+///
+/// Every BoolVec and BoolSlice stores its values in a given order, BitOrder::Msb0 unless asked otherwise.
+/// The order is used by every method reading or writing packed bytes, like BoolVec::as_bytes(),
+/// BoolVec::as_raw_slice() and binary, octal and hexadecimal printing,
+/// so that formats like Arrow validity bitmaps can be used in place without converting them at every export.
+///
+/// Values themselves don't depend on the order: getting, setting, comparing or hashing values
+/// gives the same results whatever the order, even between a BitOrder::Msb0 and a BitOrder::Lsb0 BoolVec.
+/// ```rust
+/// use bool_vec::{boolvec, BitOrder, BoolVec};
+///
+/// // Arrow validity bitmaps store the first value in the least significant bit
+/// let validity = [0b0000_0101];
+///
+/// let mut bv = BoolVec::from_bytes_with_order(validity, 3, BitOrder::Lsb0).unwrap();
+///
+/// assert_eq!(bv, boolvec![true, false, true]);
+/// assert_eq!(bv.bit_order(), BitOrder::Lsb0);
+///
+/// bv.push(true);
+///
+/// // Bytes are exported in the order of the BoolVec
+/// assert_eq!(bv.as_bytes(), [0b0000_1101]);
+/// assert_eq!(format!("{bv:08b}"), "[00001101]");
+///
+/// // Other orders can still be asked for explicitly
+/// assert_eq!(bv.to_bytes_with_order(BitOrder::Msb0), vec![0b1011_0000]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BitOrder {
+    /// The first value of each byte is its most significant bit
+    #[default]
+    Msb0,
+
+    /// The first value of each byte is its least significant bit
+    Lsb0,
+}
+
+impl BitOrder {
+    /// Converts a byte packed in this order to one packed most significant bit first, or vice versa
+    pub(crate) fn convert(self, byte: u8) -> u8 {
+        match self {
+            Self::Msb0 => byte,
+            Self::Lsb0 => byte.reverse_bits(),
+        }
+    }
+
+    /// Same as BitOrder::convert(), for each of the bytes making up `word` in memory
+    pub(crate) fn convert_word(self, word: u64) -> u64 {
+        match self {
+            Self::Msb0 => word,
+            // Reversing the whole word also reverses the order of its bytes, which is then restored
+            Self::Lsb0 => word.reverse_bits().swap_bytes(),
+        }
+    }
+
+    /// Converts a word of a BoolVec stored in `from` order to one stored in this order
+    pub(crate) fn convert_word_from(self, from: BitOrder, word: u64) -> u64 {
+        self.convert_word(from.convert_word(word))
+    }
+}
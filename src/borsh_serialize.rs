@@ -0,0 +1,84 @@
+//! Serialization of BoolVec and BoolSlice with borsh, only available with the `borsh` feature.
+//!
+//! Values are serialized as their number, as a u64 just like borsh serializes a usize,
+//! followed by the packed bytes storing them, most significant bit first whatever the bit order of the BoolVec, just like BoolSlice::bytes() yields them.
+//! The number of bytes isn't serialized, since it's always just enough to hold the values.
+//!
+//! Bits of the last byte past the last value are always zero, and deserializing bytes where they aren't fails,
+//! so that each BoolVec has a single serialized form.
+//! ```rust
+//! use bool_vec::{boolvec, BoolVec};
+//!
+//! let bv = boolvec![true, false, true, true, false, false, false, false, true];
+//!
+//! let bytes = borsh::to_vec(&bv).unwrap();
+//!
+//! assert_eq!(bytes, [9, 0, 0, 0, 0, 0, 0, 0, 0b1011_0000, 0b1000_0000]);
+//! assert_eq!(borsh::from_slice::<BoolVec>(&bytes).unwrap(), bv);
+//!
+//! // Slices are serialized just like the BoolVec they would be copied into
+//! assert_eq!(borsh::to_vec(&bv.slice(1..4)).unwrap(), [3, 0, 0, 0, 0, 0, 0, 0, 0b0110_0000]);
+//!
+//! // The last value stops at the first bit of the second byte
+//! assert!(borsh::from_slice::<BoolVec>(&[9, 0, 0, 0, 0, 0, 0, 0, 0b1011_0000, 0b1100_0000]).is_err());
+//! ```
+
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{bytes_for, Allocator, BoolSlice, BoolVec};
+
+/// Serializes the number of values followed by the packed bytes storing them
+fn serialize_packed<W: Write>(len: usize, bytes: &[u8], writer: &mut W) -> Result<()> {
+    (len as u64).serialize(writer)?;
+    writer.write_all(bytes)
+}
+
+impl BorshSerialize for BoolSlice<'_> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        serialize_packed(self.len(), &self.bytes().collect::<Vec<u8>>(), writer)
+    }
+}
+
+impl<A: Allocator> BorshSerialize for BoolVec<A> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        serialize_packed(self.len(), &self.msb0_bytes(), writer)
+    }
+}
+
+impl BorshDeserialize for BoolVec {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let len = usize::try_from(u64::deserialize_reader(reader)?).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "number of values doesn't fit in a usize",
+            )
+        })?;
+
+        let byte_len = bytes_for(len);
+
+        // Bytes are read as they come instead of being allocated upfront,
+        // so that a corrupted number of values can't allocate more than the reader holds
+        let mut bytes = Vec::new();
+        reader.take(byte_len as u64).read_to_end(&mut bytes)?;
+
+        if bytes.len() < byte_len {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                format!("{} bytes can't hold {len} values", bytes.len()),
+            ));
+        }
+
+        let bool_vec = BoolVec::from_bytes(&bytes, len).expect("bytes should hold every value");
+
+        if bool_vec.as_bytes().last() != bytes.last() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "bits past the last value should be zero",
+            ));
+        }
+
+        Ok(bool_vec)
+    }
+}
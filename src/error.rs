@@ -0,0 +1,101 @@
+//! Errors returned by the fallible methods of BoolVec and BoolSlice.
+
+use std::error::Error;
+use std::fmt::Display;
+
+/// Error returned by the `try_` methods of BoolVec and BoolSlice, like BoolSlice::try_get(),
+/// and when parsing a BoolVec from a string
+/// ```rust
+/// use bool_vec::{boolvec, BoolVecError};
+///
+/// let bv = boolvec![true, false];
+///
+/// let error = bv.try_get(5).unwrap_err();
+///
+/// assert_eq!(error, BoolVecError::IndexOutOfBounds { index: 5, len: 2 });
+/// assert_eq!(error.to_string(), "index 5 is out of bounds for a length of 2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BoolVecError {
+    /// An index overflowed the length of the BoolVec or BoolSlice
+    IndexOutOfBounds {
+        /// The offending index
+        index: usize,
+
+        /// The length at the time of the call
+        len: usize,
+    },
+
+    /// A string being parsed into a BoolVec contained a character which isn't a digit or a separator
+    InvalidCharacter {
+        /// The offending character
+        character: char,
+
+        /// Its byte offset in the string
+        index: usize,
+    },
+
+    /// A string being parsed into a BoolVec held fewer values than requested
+    NotEnoughValues {
+        /// The number of values requested
+        bit_len: usize,
+
+        /// The number of values held by the string
+        len: usize,
+    },
+
+    /// A BoolSlice held more values than the integer it was converted to has bits
+    TooManyValues {
+        /// The number of values held by the BoolSlice
+        len: usize,
+
+        /// The number of bits of the integer
+        max: usize,
+    },
+
+    /// Two BoolVecs being combined with LengthPolicy::Exact had different lengths
+    LengthMismatch {
+        /// The length of the BoolVec the method was called on
+        len: usize,
+
+        /// The length of the other BoolVec
+        other_len: usize,
+    },
+
+    /// A string being decoded with BoolVec::from_base64() wasn't valid base64
+    #[cfg(feature = "base64")]
+    InvalidBase64(base64::DecodeError),
+}
+
+impl Display for BoolVecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IndexOutOfBounds { index, len } => {
+                write!(f, "index {index} is out of bounds for a length of {len}")
+            }
+            Self::InvalidCharacter { character, index } => {
+                write!(f, "invalid character {character:?} at index {index}")
+            }
+            Self::NotEnoughValues { bit_len, len } => {
+                write!(
+                    f,
+                    "expected at least {bit_len} values, but only {len} were found"
+                )
+            }
+            Self::TooManyValues { len, max } => {
+                write!(f, "{len} values don't fit in an integer of {max} bits")
+            }
+            Self::LengthMismatch { len, other_len } => {
+                write!(
+                    f,
+                    "expected a length of {len}, but the other BoolVec has a length of {other_len}"
+                )
+            }
+            #[cfg(feature = "base64")]
+            Self::InvalidBase64(error) => write!(f, "invalid base64: {error}"),
+        }
+    }
+}
+
+impl Error for BoolVecError {}
@@ -0,0 +1,87 @@
+//! C functions exchanging BoolVecs with foreign code, only available with the `ffi` feature.
+//!
+//! BoolVecs are handed to C as opaque pointers, which stay owned by Rust until they're passed to bool_vec_free().
+//! Their values can be read in place as packed bytes, most significant bit first just like BoolVec::as_bytes() returns them,
+//! and foreign buffers packed the same way are copied into new BoolVecs.
+//! ```rust
+//! use bool_vec::boolvec;
+//! use bool_vec::ffi::*;
+//!
+//! let buffer = [0b1011_0000, 0b1000_0000];
+//!
+//! unsafe {
+//!     let bv = bool_vec_from_bytes(buffer.as_ptr(), 9);
+//!
+//!     assert_eq!(bool_vec_bit_len(bv), 9);
+//!     assert_eq!(bool_vec_byte_len(bv), 2);
+//!     assert_eq!(*bool_vec_bytes(bv), 0b1011_0000);
+//!     assert_eq!(*bv, boolvec![true, false, true, true, false, false, false, false, true]);
+//!
+//!     bool_vec_free(bv);
+//! }
+//! ```
+
+use std::ptr;
+use std::slice;
+
+use crate::BoolVec;
+
+/// Copies the first `bit_len` values packed in the bytes pointed by `bytes` into a new BoolVec,
+/// returning an owned pointer to it which must be freed with bool_vec_free()
+///
+/// # Safety
+/// `bytes` must be valid for reading `(bit_len + 7) / 8` bytes. It may be null if `bit_len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn bool_vec_from_bytes(bytes: *const u8, bit_len: usize) -> *mut BoolVec {
+    let bytes = match bit_len {
+        0 => &[],
+        _ => slice::from_raw_parts(bytes, bit_len.div_ceil(8)),
+    };
+
+    let bool_vec = BoolVec::from_bytes(bytes, bit_len).expect("bytes should hold every value");
+
+    Box::into_raw(Box::new(bool_vec))
+}
+
+/// Returns a pointer to the packed bytes storing the values of `bool_vec`,
+/// which stays valid until `bool_vec` is modified or freed. Bits past the last value are always zero.
+/// Returns null if `bool_vec` holds no values
+///
+/// # Safety
+/// `bool_vec` must have been returned by bool_vec_from_bytes() and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn bool_vec_bytes(bool_vec: *const BoolVec) -> *const u8 {
+    match (*bool_vec).as_bytes() {
+        [] => ptr::null(),
+        bytes => bytes.as_ptr(),
+    }
+}
+
+/// Returns the number of packed bytes storing the values of `bool_vec`
+///
+/// # Safety
+/// `bool_vec` must have been returned by bool_vec_from_bytes() and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn bool_vec_byte_len(bool_vec: *const BoolVec) -> usize {
+    (*bool_vec).as_bytes().len()
+}
+
+/// Returns the number of values held by `bool_vec`
+///
+/// # Safety
+/// `bool_vec` must have been returned by bool_vec_from_bytes() and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn bool_vec_bit_len(bool_vec: *const BoolVec) -> usize {
+    (*bool_vec).len()
+}
+
+/// Frees a BoolVec returned by bool_vec_from_bytes(). Does nothing if `bool_vec` is null
+///
+/// # Safety
+/// `bool_vec` must have been returned by bool_vec_from_bytes() and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn bool_vec_free(bool_vec: *mut BoolVec) {
+    if !bool_vec.is_null() {
+        drop(Box::from_raw(bool_vec));
+    }
+}
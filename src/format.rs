@@ -0,0 +1,106 @@
+//! Configurable printing of the values of a BoolSlice, as a string of characters.
+
+use std::fmt::{Display, Write};
+
+use crate::BoolSlice;
+
+/// Options controlling how BoolSlice::display_with() prints values, one character per value.
+///
+/// The default options print values just like pretty Display printing does,
+/// separating every group of 8 values with an underscore:
+/// ```rust
+/// use bool_vec::{boolvec, FormatOptions};
+///
+/// let bv = boolvec![true, false, true, true, false];
+///
+/// let options = FormatOptions {
+///     group: 2,
+///     separator: " ",
+///     true_char: '#',
+///     false_char: '.',
+/// };
+///
+/// assert_eq!(bv.display_with(options).to_string(), "#. ## .");
+/// assert_eq!(bv.display_with(FormatOptions::default()).to_string(), format!("{bv:#}"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FormatOptions<'a> {
+    /// Number of values in each group. Values aren't grouped at all if this is 0
+    pub group: usize,
+
+    /// Printed between two groups of values
+    pub separator: &'a str,
+
+    /// Printed for each `true` value
+    pub true_char: char,
+
+    /// Printed for each `false` value
+    pub false_char: char,
+}
+
+impl Default for FormatOptions<'_> {
+    fn default() -> Self {
+        Self {
+            group: 8,
+            separator: "_",
+            true_char: '1',
+            false_char: '0',
+        }
+    }
+}
+
+impl FormatOptions<'_> {
+    /// Options printing values as ones and zeros without grouping them, like regular Display printing
+    pub(crate) const UNGROUPED: FormatOptions<'static> = FormatOptions {
+        group: 0,
+        separator: "",
+        true_char: '1',
+        false_char: '0',
+    };
+
+    /// Writes a character for each value of `slice`, separating groups of values
+    fn write_values<W: Write>(&self, slice: BoolSlice<'_>, writer: &mut W) -> std::fmt::Result {
+        for (int_index, value) in slice.iter().enumerate() {
+            if self.group > 0 && int_index > 0 && int_index % self.group == 0 {
+                writer.write_str(self.separator)?;
+            }
+
+            writer.write_char(if value {
+                self.true_char
+            } else {
+                self.false_char
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Prints the values of `slice`, applying padding to the whole string
+    pub(crate) fn fmt_slice(
+        &self,
+        slice: BoolSlice<'_>,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write_values(slice, f);
+        }
+
+        let mut values = String::with_capacity(slice.len());
+        self.write_values(slice, &mut values)?;
+
+        f.pad(&values)
+    }
+}
+
+/// Prints the values of a BoolSlice with custom FormatOptions, returned by BoolSlice::display_with()
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayWith<'a> {
+    pub(crate) slice: BoolSlice<'a>,
+    pub(crate) options: FormatOptions<'a>,
+}
+
+impl Display for DisplayWith<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.options.fmt_slice(self.slice, f)
+    }
+}
@@ -0,0 +1,186 @@
+//! Conversions between BoolVec and the bit vectors of other crates, each one only available with the feature named after that crate.
+//!
+//! Since BoolVec::from() builds a BoolVec from a slice of booleans, conversions from other bit vectors
+//! go through `.into()` or `From::from()` instead.
+
+#[cfg(feature = "bitvec")]
+mod bitvec_conversions {
+    use bitvec::order::BitOrder;
+    use bitvec::slice::BitSlice;
+    use bitvec::store::BitStore;
+    use bitvec::vec::BitVec;
+
+    use crate::{Allocator, BoolSlice, BoolVec};
+
+    /// Copies the values of a bitvec BitSlice, whatever its ordering and storage
+    /// ```rust
+    /// use bitvec::prelude::*;
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let bits = bits![u16, Lsb0; 1, 0, 1, 1];
+    ///
+    /// let bv: BoolVec = bits.into();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, true]);
+    /// ```
+    impl<T: BitStore, O: BitOrder> From<&BitSlice<T, O>> for BoolVec {
+        fn from(bits: &BitSlice<T, O>) -> Self {
+            bits.iter().by_vals().collect()
+        }
+    }
+
+    impl<T: BitStore, O: BitOrder> From<BitVec<T, O>> for BoolVec {
+        fn from(bits: BitVec<T, O>) -> Self {
+            bits.as_bitslice().into()
+        }
+    }
+
+    /// Copies the values into a bitvec BitVec, whatever its ordering and storage
+    /// ```rust
+    /// use bitvec::prelude::*;
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true];
+    ///
+    /// let bits: BitVec<u8, Msb0> = bv.slice(1..).into();
+    ///
+    /// assert_eq!(bits, bits![0, 1, 1]);
+    /// ```
+    impl<T: BitStore, O: BitOrder> From<BoolSlice<'_>> for BitVec<T, O> {
+        fn from(slice: BoolSlice<'_>) -> Self {
+            slice.iter().collect()
+        }
+    }
+
+    impl<T: BitStore, O: BitOrder, A: Allocator> From<BoolVec<A>> for BitVec<T, O> {
+        fn from(bool_vec: BoolVec<A>) -> Self {
+            bool_vec.as_slice().into()
+        }
+    }
+}
+
+#[cfg(feature = "bit-vec")]
+mod bit_vec_conversions {
+    use bit_vec::BitVec;
+
+    use crate::{Allocator, BoolSlice, BoolVec};
+
+    /// Copies the values of a bit-vec BitVec, which packs them most significant bit first too,
+    /// so its bytes are copied all at once
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let bits = bit_vec::BitVec::from_fn(10, |index| index % 3 == 0);
+    ///
+    /// let bv: BoolVec = (&bits).into();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, false, true, false, false, true, false, false, true]);
+    /// ```
+    impl From<&BitVec> for BoolVec {
+        fn from(bits: &BitVec) -> Self {
+            BoolVec::from_bytes(bits.to_bytes(), bits.len()).expect("bytes should hold every value")
+        }
+    }
+
+    impl From<BitVec> for BoolVec {
+        fn from(bits: BitVec) -> Self {
+            (&bits).into()
+        }
+    }
+
+    /// Copies the values into a bit-vec BitVec, copying the packed bytes storing them all at once
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true];
+    ///
+    /// let bits: bit_vec::BitVec = bv.slice(1..).into();
+    ///
+    /// assert!(bits.eq_vec(&[false, true, true]));
+    /// ```
+    impl From<BoolSlice<'_>> for BitVec {
+        fn from(slice: BoolSlice<'_>) -> Self {
+            let mut bits = BitVec::from_bytes(&slice.bytes().collect::<Vec<u8>>());
+            bits.truncate(slice.len());
+
+            bits
+        }
+    }
+
+    impl<A: Allocator> From<BoolVec<A>> for BitVec {
+        fn from(bool_vec: BoolVec<A>) -> Self {
+            let mut bits = BitVec::from_bytes(&bool_vec.msb0_bytes());
+            bits.truncate(bool_vec.len());
+
+            bits
+        }
+    }
+}
+
+#[cfg(feature = "fixedbitset")]
+mod fixedbitset_conversions {
+    use fixedbitset::FixedBitSet;
+
+    use crate::{Allocator, BoolSlice, BoolVec};
+
+    /// Copies the values of a FixedBitSet, holding as many values as the FixedBitSet
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    /// use fixedbitset::FixedBitSet;
+    ///
+    /// let mut set = FixedBitSet::with_capacity(4);
+    /// set.insert(2);
+    ///
+    /// let bv: BoolVec = (&set).into();
+    ///
+    /// assert_eq!(bv, boolvec![false, false, true, false]);
+    /// ```
+    impl From<&FixedBitSet> for BoolVec {
+        fn from(set: &FixedBitSet) -> Self {
+            let mut bool_vec = BoolVec::with_capacity(set.len());
+            bool_vec.resize(set.len(), false);
+
+            for index in set.ones() {
+                bool_vec.set(index, true);
+            }
+
+            bool_vec
+        }
+    }
+
+    impl From<FixedBitSet> for BoolVec {
+        fn from(set: FixedBitSet) -> Self {
+            (&set).into()
+        }
+    }
+
+    /// Copies the values into a FixedBitSet, with the indexes of the `true` values as its members
+    /// ```rust
+    /// use bool_vec::boolvec;
+    /// use fixedbitset::FixedBitSet;
+    ///
+    /// let bv = boolvec![true, false, true, true];
+    ///
+    /// let set: FixedBitSet = bv.slice(1..).into();
+    ///
+    /// assert_eq!(set.len(), 3);
+    /// assert_eq!(set.ones().collect::<Vec<_>>(), vec![1, 2]);
+    /// ```
+    impl From<BoolSlice<'_>> for FixedBitSet {
+        fn from(slice: BoolSlice<'_>) -> Self {
+            let mut set = FixedBitSet::with_capacity(slice.len());
+
+            for (index, value) in slice.iter().enumerate() {
+                set.set(index, value);
+            }
+
+            set
+        }
+    }
+
+    impl<A: Allocator> From<BoolVec<A>> for FixedBitSet {
+        fn from(bool_vec: BoolVec<A>) -> Self {
+            bool_vec.as_slice().into()
+        }
+    }
+}
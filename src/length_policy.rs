@@ -0,0 +1,30 @@
+//! How set operations combine two BoolVecs of different lengths.
+
+/// How set operations like BoolVec::union() treat two BoolVecs of different lengths.
+///
+/// A BoolVec can be seen as the set of the indexes of its `true` values,
+/// so the values past the end of the shortest one are usually treated as `false`.
+/// ```rust
+/// use bool_vec::{boolvec, BoolVecError, LengthPolicy};
+///
+/// let bv1 = boolvec![true, false];
+/// let bv2 = boolvec![false, false, true];
+///
+/// assert_eq!(
+///     bv1.union(&bv2, LengthPolicy::ZeroExtend),
+///     Ok(boolvec![true, false, true])
+/// );
+/// assert_eq!(
+///     bv1.union(&bv2, LengthPolicy::Exact),
+///     Err(BoolVecError::LengthMismatch { len: 2, other_len: 3 })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LengthPolicy {
+    /// The shortest BoolVec is extended with `false` values, so the result is as long as the longest one
+    #[default]
+    ZeroExtend,
+
+    /// Both BoolVecs must have the same length, otherwise BoolVecError::LengthMismatch is returned
+    Exact,
+}
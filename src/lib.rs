@@ -1,4 +1,53 @@
-use std::fmt::{Binary, Debug, Write};
+use std::cmp::Ordering;
+use std::fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex, Write};
+use std::hash::{Hash, Hasher};
+use std::io::{self, Read};
+use std::iter::FusedIterator;
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, RangeBounds, Shl,
+    ShlAssign, Shr, ShrAssign,
+};
+use std::ptr::NonNull;
+use std::str::FromStr;
+
+#[cfg(feature = "rkyv")]
+pub mod archive;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "rayon")]
+pub mod par;
+
+mod bit_mut;
+mod bit_order;
+#[cfg(feature = "borsh")]
+mod borsh_serialize;
+mod error;
+mod format;
+mod interop;
+mod length_policy;
+mod rank_select;
+#[cfg(feature = "serde")]
+mod serialize;
+mod set;
+#[cfg(feature = "simd")]
+mod simd;
+mod slice;
+mod words;
+
+pub use allocator_api2::alloc::{Allocator, Global};
+pub use allocator_api2::collections::TryReserveError;
+#[cfg(feature = "rkyv")]
+pub use archive::ArchivedBoolVec;
+pub use bit_mut::{BitMut, BitRef};
+pub use bit_order::BitOrder;
+pub use error::BoolVecError;
+pub use format::{DisplayWith, FormatOptions};
+pub use length_policy::LengthPolicy;
+pub use rank_select::RankSelect;
+pub use set::{BoolSet, BoolSetIter};
+pub use slice::{BoolSequence, BoolSlice, BoolSliceIndex, BoolSliceMut, MaskedSource};
+
+use words::Words;
 
 #[doc = include_str!("../README.md")]
 #[cfg(doctest)]
@@ -15,7 +64,15 @@ struct ReadmeDoctests;
 /// ---
 ///
 /// # Capacity
-/// Capacity is always a multiple of 8, check BoolVec.capacity() docs for more infos.
+/// Capacity is always a multiple of 64, check BoolVec.capacity() docs for more infos.
+///
+/// Up to 128 booleans are stored inline, without allocating anything (check BoolVec.is_inline() docs).
+/// When a BoolVec runs out of capacity, the number of words (of 64 booleans each) is doubled,
+/// just like a normal Vec would do.
+///
+/// # Allocator
+/// Memory is allocated with `A`, which is the global allocator unless the BoolVec
+/// is created with BoolVec::new_in() or BoolVec::with_capacity_in().
 ///
 /// # Length
 /// Length works like the length of a normal Vec.
@@ -36,7 +93,7 @@ struct ReadmeDoctests;
 /// println!("{bv:#?}");
 /// ```
 ///
-/// ## You can print the underlying bytes of the BoolVec if you need them
+/// ## You can print the underlying bytes of the BoolVec if you need them, packed in its bit order
 /// ```rust
 /// use bool_vec::boolvec;
 ///
@@ -46,32 +103,176 @@ struct ReadmeDoctests;
 /// assert_eq!(format!("{bv:b}"), "[11011111, 10000000]")
 /// // You can also apply padding and pretty printing. See formatting specifiers.
 /// ```
-#[derive(Default, Clone)]
-pub struct BoolVec {
-    /// The underlying vector holding the bytes
-    bytes: Vec<u8>,
-
-    /// The maximum capacity of the vector in bits (how many values the BoolVec can hold without reallocating)
-    capacity: usize,
+///
+/// ## Or print them in octal or hexadecimal, which is more compact for long BoolVecs
+/// ```rust
+/// use bool_vec::boolvec;
+///
+/// let mut bv = boolvec![true; 9];
+/// bv.set(2, false).unwrap();
+///
+/// assert_eq!(format!("{bv:o}"), "[337, 200]");
+/// assert_eq!(format!("{bv:x}"), "[df, 80]");
+/// assert_eq!(format!("{bv:02X}"), "[DF, 80]");
+/// assert_eq!(format!("{:#x}", boolvec![true; 3]), "[\n    0xe0\n]");
+/// assert_eq!(format!("{:#o}", boolvec![true; 3]), "[\n    0o340\n]");
+/// ```
+#[derive(Clone)]
+pub struct BoolVec<A: Allocator = Global> {
+    /// The underlying storage holding the values, 64 at a time.
+    /// Its capacity determines the capacity of the BoolVec.
+    /// Up to INLINE_WORDS words are stored inline, without allocating.
+    ///
+    /// When read as bytes in memory order (check BoolVec::bytes()), words hold the values
+    /// 8 per byte just like a BoolSlice expects them, regardless of the platform endianness.
+    /// Bulk operations which don't depend on the position of each bit, like combining
+    /// or comparing, process whole words instead
+    words: Words<A>,
 
     /// The length of the vector in bits (the number of values in the BoolVec)
     length: usize,
+
+    /// Order in which values are packed into each byte of the underlying storage
+    order: BitOrder,
 }
 
-/// Value used for indexing bytes inside BoolVec.bytes and Bits inside
-/// a single element of BoolVec.bytes
+/// Value used for indexing bytes inside BoolVec.raw_bytes() and Bits inside
+/// a single element of BoolVec.raw_bytes()
 struct BoolIndex {
-    /// Index for BoolVec.bytes
+    /// Index for BoolVec.raw_bytes()
     byte_index: usize,
 
-    /// Index for bits inside a single element of BoolVec.bytes
+    /// Index for bits inside a single element of BoolVec.raw_bytes()
     bit_index: u8,
 }
 
 /// Iterator referencing to a BoolVec
 pub struct BoolVecIter<'a> {
-    vec: &'a BoolVec,
+    slice: BoolSlice<'a>,
+    counter: usize,
+
+    /// Index right after the next value returned when iterating from the back
+    end: usize,
+}
+
+/// Iterator consuming a BoolVec
+pub struct BoolVecIntoIter<A: Allocator = Global> {
+    vec: BoolVec<A>,
+    counter: usize,
+
+    /// Index right after the next value returned when iterating from the back
+    end: usize,
+}
+
+/// Iterator over non-overlapping chunks of a BoolSlice, check BoolSlice::chunks() docs for more infos
+pub struct Chunks<'a> {
+    slice: BoolSlice<'a>,
+    chunk_size: usize,
+
+    /// Index of the first value of the next chunk
+    counter: usize,
+
+    /// Index right after the last value of the next chunk returned when iterating from the back
+    end: usize,
+}
+
+/// Iterator over overlapping windows of a BoolSlice, check BoolSlice::windows() docs for more infos
+pub struct Windows<'a> {
+    slice: BoolSlice<'a>,
+    window_size: usize,
+
+    /// Index of the first value of the next window
+    counter: usize,
+
+    /// Index right after the first value of the next window returned when iterating from the back
+    end: usize,
+}
+
+/// Iterator over overlapping windows of a BoolSlice packed into integers,
+/// check BoolSlice::windows_u64() docs for more infos
+pub struct WindowsU64<'a> {
+    slice: BoolSlice<'a>,
+    window_size: usize,
+
+    /// Index of the first value of the next window
     counter: usize,
+
+    /// Index right after the first value of the next window returned when iterating from the back
+    end: usize,
+}
+
+/// Iterator over the runs of equal consecutive values inside a BoolSlice,
+/// check BoolSlice::runs() docs for more infos
+pub struct Runs<'a> {
+    slice: BoolSlice<'a>,
+
+    /// Index of the first value of the next run
+    counter: usize,
+}
+
+/// Iterator over the indexes where two BoolSlices hold different values,
+/// check BoolSlice::differences() docs for more infos
+pub struct Differences<'a> {
+    slice: BoolSlice<'a>,
+    other: BoolSlice<'a>,
+
+    /// Index of the first value of the next byte to compare
+    counter: usize,
+
+    /// Index right after the last value to compare
+    end: usize,
+
+    /// Index of the first value of the byte being yielded
+    base: usize,
+
+    /// Bits set where the byte being yielded holds differences that weren't yielded yet
+    pending: u8,
+}
+
+/// Iterator over the packed bytes of a BoolSlice, check BoolSlice::bytes() docs for more infos
+pub struct Bytes<'a> {
+    slice: BoolSlice<'a>,
+
+    /// Index of the next byte
+    counter: usize,
+
+    /// Index right after the next byte returned when iterating from the back
+    end: usize,
+}
+
+/// Iterator over the packed bytes of a BoolSlice, 8 at a time,
+/// check BoolSlice::chunks_u64() docs for more infos
+pub struct ChunksU64<'a> {
+    slice: BoolSlice<'a>,
+
+    /// Index of the next chunk
+    counter: usize,
+
+    /// Index right after the next chunk returned when iterating from the back
+    end: usize,
+}
+
+/// Iterator removing a range of values from a BoolVec, check BoolVec::drain() docs for more infos
+pub struct Drain<'a, A: Allocator = Global> {
+    vec: &'a mut BoolVec<A>,
+
+    /// Index of the first drained value
+    start: usize,
+
+    /// Index of the next value returned by the iterator
+    counter: usize,
+
+    /// Index right after the next value returned when iterating from the back
+    back: usize,
+
+    /// Index of the first value coming after the drained range
+    end: usize,
+}
+
+/// Iterator replacing a range of values in a BoolVec, check BoolVec::splice() docs for more infos
+pub struct Splice<'a, I: Iterator<Item = bool>, A: Allocator = Global> {
+    drain: Drain<'a, A>,
+    replace_with: I,
 }
 
 impl BoolIndex {
@@ -84,24 +285,234 @@ impl BoolIndex {
     }
 }
 
+/// Magic bytes starting the header written by BoolSlice::write_to()
+const HEADER_MAGIC: [u8; 4] = *b"BOOL";
+
+/// Version of the header written by BoolSlice::write_to(), bumped whenever its layout changes
+const HEADER_VERSION: u8 = 1;
+
+/// Returns the number of bytes needed to hold `len` values
+fn bytes_for(len: usize) -> usize {
+    len.div_ceil(8)
+}
+
+/// Returns the number of words needed to hold `len` values
+fn words_for(len: usize) -> usize {
+    len.div_ceil(64)
+}
+
+/// Returns a word whose first `len` values are set, `len` being lower than 64.
+/// Values are stored most significant bit first and bytes are read in memory order,
+/// so they are laid out just like the bits of a big-endian integer.
+/// Check BoolVec::word_mask() for BoolVecs stored in another bit order
+fn word_mask(len: usize) -> u64 {
+    (!(u64::MAX >> len)).to_be()
+}
+
+/// Packs up to 8 bools into the highest bits of a byte, the first one being the most significant
+fn pack_bools(values: &[bool]) -> u8 {
+    values
+        .iter()
+        .enumerate()
+        .fold(0, |byte, (i, &value)| byte | ((value as u8) << (7 - i)))
+}
+
+/// Evaluates `f` on every possible combination of its arguments, returning each result
+/// as a byte mask. The masks are ordered as `f(false, false)`, `f(false, true)`,
+/// `f(true, false)` and `f(true, true)`
+fn truth_table<F: FnMut(bool, bool) -> bool>(mut f: F) -> [u8; 4] {
+    let mask = |value: bool| if value { u8::MAX } else { 0 };
+
+    [
+        mask(f(false, false)),
+        mask(f(false, true)),
+        mask(f(true, false)),
+        mask(f(true, true)),
+    ]
+}
+
+/// Combines two bytes bit by bit, according to a table built with truth_table()
+fn combine_bytes(a: u8, b: u8, table: [u8; 4]) -> u8 {
+    (!a & !b & table[0]) | (!a & b & table[1]) | (a & !b & table[2]) | (a & b & table[3])
+}
+
+/// Gathers the bits of `byte` selected by `mask` into the highest bits of the result,
+/// keeping their order. This is the single byte version of the BMI2 `pext` instruction,
+/// with the most significant bit coming first
+fn gather_bits(byte: u8, mut mask: u8) -> u8 {
+    let mut gathered = 0;
+    let mut count = 0;
+
+    while mask != 0 {
+        let bit = 0x80 >> mask.leading_zeros();
+
+        if byte & bit != 0 {
+            gathered |= 0x80 >> count;
+        }
+
+        mask &= !bit;
+        count += 1;
+    }
+
+    gathered
+}
+
+/// Scatters the highest bits of `byte` into the bits selected by `mask`, keeping their order
+/// and clearing the other bits. This is the single byte version of the BMI2 `pdep` instruction,
+/// with the most significant bit coming first
+fn scatter_bits(byte: u8, mut mask: u8) -> u8 {
+    let mut scattered = 0;
+    let mut count = 0;
+
+    while mask != 0 {
+        let bit = 0x80 >> mask.leading_zeros();
+
+        if byte & (0x80 >> count) != 0 {
+            scattered |= bit;
+        }
+
+        mask &= !bit;
+        count += 1;
+    }
+
+    scattered
+}
+
+/// Applies `op` to every pair of words of `lhs` and `rhs`, storing the results in `lhs`.
+/// With the `rayon` feature, huge slices are processed in parallel
+fn apply_words<F: Fn(u64, u64) -> u64 + Sync>(lhs: &mut [u64], rhs: &[u64], op: F) {
+    #[cfg(feature = "rayon")]
+    if lhs.len() * 8 >= par::PARALLEL_THRESHOLD {
+        return par::apply_words(lhs, rhs, op);
+    }
+
+    apply_words_on_thread(lhs, rhs, op);
+}
+
+/// Same as apply_words(), processing every word on the current thread.
+/// With the `simd` feature, words are processed 4 at a time when the CPU supports it
+fn apply_words_on_thread<F: Fn(u64, u64) -> u64>(lhs: &mut [u64], rhs: &[u64], op: F) {
+    #[cfg(feature = "simd")]
+    if simd::apply_words(lhs, rhs, &op) {
+        return;
+    }
+
+    for (a, b) in lhs.iter_mut().zip(rhs) {
+        *a = op(*a, *b);
+    }
+}
+
+/// Returns the number of ones in `bytes`, 8 bytes at a time.
+/// With the `rayon` feature, huge slices are processed in parallel,
+/// while with the `simd` feature, bytes are counted 32 at a time when the CPU supports it
+fn count_ones_bytes(bytes: &[u8]) -> usize {
+    #[cfg(feature = "rayon")]
+    if bytes.len() >= par::PARALLEL_THRESHOLD {
+        return par::count_ones_bytes(bytes);
+    }
+
+    #[cfg(feature = "simd")]
+    if let Some(ones) = simd::count_ones_bytes(bytes) {
+        return ones;
+    }
+
+    let chunks = bytes.chunks_exact(8);
+    let remainder = chunks.remainder();
+
+    let ones: usize = chunks
+        .map(|chunk| u64::from_ne_bytes(chunk.try_into().unwrap()).count_ones() as usize)
+        .sum();
+
+    ones + remainder
+        .iter()
+        .map(|byte| byte.count_ones() as usize)
+        .sum::<usize>()
+}
+
+/// Sets every byte of `bytes` to `value`.
+/// With the `rayon` feature, huge slices are processed in parallel
+fn fill_bytes(bytes: &mut [u8], value: u8) {
+    #[cfg(feature = "rayon")]
+    if bytes.len() >= par::PARALLEL_THRESHOLD {
+        return par::fill_bytes(bytes, value);
+    }
+
+    bytes.fill(value);
+}
+
+/// Converts any range into a `(start, end)` pair of indexes, with `end` excluded.
+/// Panics just like slice indexing does if the range is invalid for the given length
+fn range_to_indexes<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if start > end {
+        panic!("slice index starts at {start} but ends at {end}");
+    }
+
+    if end > len {
+        panic!("range end index {end} out of range for slice of length {len}");
+    }
+
+    (start, end)
+}
+
+/// Converts any range into a `(start, end)` pair of indexes, with `end` excluded.
+/// Returns None if the range is invalid for the given length
+fn checked_range_to_indexes<R: RangeBounds<usize>>(range: R, len: usize) -> Option<(usize, usize)> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if start > end || end > len {
+        return None;
+    }
+
+    Some((start, end))
+}
+
 impl BoolVec {
-    /// Allocate empty BoolVec with capacity: 0 and len: 0
+    /// Create empty BoolVec with len: 0, without allocating.
+    /// Its capacity is 128, since up to 128 booleans are stored inline
     /// ```rust
     /// use bool_vec::{boolvec, BoolVec};
     /// let bv = BoolVec::new();
     ///
     /// assert_eq!(bv, boolvec![]);
+    /// assert_eq!(bv.capacity(), 128);
+    /// assert!(bv.is_inline());
     /// ```
     pub fn new() -> Self {
         Self {
-            bytes: Vec::new(),
-            capacity: 0,
+            words: Words::new_in(Global),
             length: 0,
+            order: BitOrder::Msb0,
         }
     }
 
-    #[allow(clippy::slow_vector_initialization)]
-    /// Allocate empty BoolVec with specified capacity and len: 0
+    /// Allocate empty BoolVec with specified capacity and len: 0.
+    /// Nothing is allocated if `capacity` is 128 or less, since those booleans are stored inline
     /// ```rust
     /// use bool_vec::{boolvec, BoolVec};
     /// let bv1 = BoolVec::with_capacity(3);
@@ -110,27 +521,43 @@ impl BoolVec {
     /// bv2.pop();
     /// bv2.pop();
     ///
-    /// assert_eq!(bv1.capacity(), 8);
+    /// assert_eq!(bv1.capacity(), 128);
     /// assert_eq!(bv1.capacity(), bv2.capacity());
+    ///
+    /// let bv3 = BoolVec::with_capacity(200);
+    ///
+    /// assert_eq!(bv3.capacity(), 256);
     /// ```
-    /// To see why capacity in this case is 8 please do check BoolVec::capacity() documentation
+    /// To see why capacity in this case is 128 please do check BoolVec::capacity() documentation
     pub fn with_capacity(capacity: usize) -> Self {
-        if capacity == 0 {
-            return Self::new();
-        }
-
-        let byte_capacity = ((capacity - 1) / 8) + 1;
-        let mut bytes = Vec::with_capacity(byte_capacity);
-
-        bytes.resize(byte_capacity, 0);
-
         Self {
-            bytes,
-            capacity: byte_capacity * 8,
+            words: Words::zeroed_in(words_for(capacity), Global),
             length: 0,
+            order: BitOrder::Msb0,
         }
     }
 
+    /// Create empty BoolVec with len: 0 storing its values in the given bit order, without allocating.
+    ///
+    /// Values are the same whatever the order, which only changes how they're packed into bytes,
+    /// like the ones returned by BoolVec::as_bytes(). Check BitOrder docs for more infos
+    /// ```rust
+    /// use bool_vec::{boolvec, BitOrder, BoolVec};
+    ///
+    /// let mut bv = BoolVec::with_bit_order(BitOrder::Lsb0);
+    ///
+    /// bv.extend_from_slice(&[true, false, true, true]);
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, true]);
+    /// assert_eq!(bv.as_bytes(), [0b0000_1101]);
+    /// ```
+    pub fn with_bit_order(order: BitOrder) -> Self {
+        let mut bool_vec = Self::new();
+        bool_vec.order = order;
+
+        bool_vec
+    }
+
     /// Create BoolVec from a slice or vector of booleans
     /// ```rust
     /// use bool_vec::{boolvec, BoolVec};
@@ -142,391 +569,3405 @@ impl BoolVec {
         let slice = slice.as_ref();
 
         let mut bool_vec = Self::with_capacity(slice.len());
-
-        for b in slice {
-            bool_vec.push(*b);
-        }
+        bool_vec.extend_from_slice(slice);
 
         bool_vec
     }
 
-    /// Get bool value from a BoolVec. Returns None if index overflows BoolVec.len()
+    /// Create BoolVec holding the first `bit_len` values of already packed bytes, like a `Vec<u8>` or a slice.
+    /// Returns None if `bytes` holds less than `bit_len` values.
+    ///
+    /// Values are read most significant bit first, just like with BoolSlice::from_bytes(),
+    /// and bits coming after `bit_len` are cleared.
+    /// Since values are stored in words of 8 bytes, the bytes are copied all at once instead of one value at a time.
     /// ```rust
-    /// use bool_vec::boolvec;
+    /// use bool_vec::{boolvec, BoolVec};
     ///
-    /// let bv = boolvec![true, false, true];
+    /// let bytes = vec![0b1011_0000, 0b1111_1111];
     ///
-    /// assert_eq!(bv.get(0), Some(true));
-    /// assert_eq!(bv.get(4), None);
+    /// let bv = BoolVec::from_bytes(&bytes, 10).unwrap();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, true, false, false, false, false, true, true]);
+    /// assert_eq!(bv.as_bytes(), [0b1011_0000, 0b1100_0000]);
+    ///
+    /// assert!(BoolVec::from_bytes(bytes, 17).is_none());
     /// ```
-    pub fn get(&self, int_index: usize) -> Option<bool> {
-        let index = BoolIndex::from(int_index);
-
-        if int_index >= self.length {
-            return None;
-        }
-
-        Some((self.bytes[index.byte_index] << index.bit_index) & 128 == 128)
+    pub fn from_bytes<S: AsRef<[u8]>>(bytes: S, bit_len: usize) -> Option<Self> {
+        Self::from_bytes_with_order(bytes, bit_len, BitOrder::Msb0)
     }
 
-    /// Set bool value in vector. Returns None if index overflows BoolVec.len()
+    /// Same as BoolVec::from_bytes(), reading the values of each byte in the given order.
+    /// The BoolVec keeps storing its values in that order, so bytes are copied as they are
+    /// and exported back the same way.
     /// ```rust
-    /// use bool_vec::boolvec;
+    /// use bool_vec::{boolvec, BitOrder, BoolVec};
     ///
-    /// let mut bv = boolvec![true, false, true];
-    /// bv.set(0, false);
+    /// let bytes = [0b0000_1101, 0b1111_0011];
     ///
-    /// assert_eq!(bv.get(0), Some(false));
-    /// assert_eq!(bv.set(4, true), None);
+    /// let bv = BoolVec::from_bytes_with_order(bytes, 10, BitOrder::Lsb0).unwrap();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, true, false, false, false, false, true, true]);
+    /// assert_eq!(bv.as_bytes(), [0b0000_1101, 0b0000_0011]);
     /// ```
-    pub fn set(&mut self, int_index: usize, value: bool) -> Option<()> {
-        let index = BoolIndex::from(int_index);
-
-        if int_index >= self.length {
-            return None;
-        }
+    pub fn from_bytes_with_order<S: AsRef<[u8]>>(
+        bytes: S,
+        bit_len: usize,
+        order: BitOrder,
+    ) -> Option<Self> {
+        let byte_len = bytes_for(bit_len);
+        let bytes = bytes.as_ref().get(..byte_len)?;
+
+        let mut bool_vec = Self::with_capacity(bit_len);
+        bool_vec.raw_bytes_mut()[..byte_len].copy_from_slice(bytes);
+
+        bool_vec.length = bit_len;
+        bool_vec.order = order;
+        bool_vec.clear_unused_bits();
+
+        Some(bool_vec)
+    }
 
-        let byte = &mut self.bytes[index.byte_index];
+    /// Create BoolVec holding the `bits` least significant bits of `value`, most significant bit first,
+    /// just like they're written in a binary literal.
+    ///
+    /// Panics if `bits` is greater than 128.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// assert_eq!(BoolVec::from_uint(0b1101u8, 4), boolvec![true, true, false, true]);
+    /// assert_eq!(BoolVec::from_uint(0b1101u32, 6), boolvec![false, false, true, true, false, true]);
+    /// assert_eq!(BoolVec::from_uint(u64::MAX, 0), boolvec![]);
+    /// ```
+    pub fn from_uint<T: Into<u128>>(value: T, bits: usize) -> Self {
+        assert!(bits <= 128, "can't take {bits} bits from a u128");
 
-        if value {
-            // Assign one to that single bit
-            *byte |= 1 << (7 - index.bit_index);
-        } else {
-            // Assign zero to that single bit
-            *byte &= !(1 << (7 - index.bit_index));
-        }
+        let value = value.into().checked_shl(128 - bits as u32).unwrap_or(0);
 
-        Some(())
+        Self::from_bytes(value.to_be_bytes(), bits).expect("16 bytes should hold every value")
     }
 
-    /// Alters BoolVec by negating the value at the specified index.
-    /// Returns None if index overflows BoolVec.len(), otherwise returns Some(negated value)
+    /// Create BoolVec holding the 8 bits of `value`, most significant bit first
     /// ```rust
-    /// use bool_vec::boolvec;
+    /// use bool_vec::{boolvec, BoolVec};
     ///
-    /// let mut bv = boolvec![true, false, true];
+    /// let bv = BoolVec::from_u8(0b1000_0001);
     ///
-    /// assert_eq!(bv.negate(0), Some(false));
-    /// assert_eq!(bv.get(0), Some(false));
+    /// assert_eq!(bv, boolvec![true, false, false, false, false, false, false, true]);
     /// ```
-    pub fn negate(&mut self, int_index: usize) -> Option<bool> {
-        // Index overflow check is done by self.get()
-        let negated_value = !self.get(int_index)?;
-        let _ = self.set(int_index, negated_value);
-
-        Some(negated_value)
+    pub fn from_u8(value: u8) -> Self {
+        Self::from_uint(value, 8)
     }
 
-    /// Appends a bool to the back of a BoolVec
+    /// Create BoolVec holding the 16 bits of `value`, most significant bit first
     /// ```rust
-    /// use bool_vec::boolvec;
-    ///
-    /// let mut bv = boolvec![true; 8];
+    /// use bool_vec::BoolVec;
     ///
-    /// assert_eq!(bv.capacity(), bv.bytes_capacity()*8);
-    /// assert_eq!(bv.len(), 8);
-    /// assert_eq!(bv.bytes_len(), 1);
-    ///
-    /// bv.push(false);
+    /// let bv = BoolVec::from_u16(0x8001);
     ///
-    /// assert_eq!(bv.capacity(), bv.bytes_capacity()*8);
-    /// assert_eq!(bv.len(), 9);
-    /// assert_eq!(bv.bytes_len(), 2);
-    ///
-    /// assert_eq!(bv.get(8), Some(false));
+    /// assert_eq!(bv.len(), 16);
+    /// assert_eq!(bv.count_ones(), 2);
+    /// assert_eq!(bv.get(0), Some(true));
     /// ```
-    pub fn push(&mut self, value: bool) {
-        self.length += 1;
-
-        if self.length > self.capacity {
-            self.bytes.push(0);
-            self.capacity = self.bytes.capacity()*8;
-        }
+    pub fn from_u16(value: u16) -> Self {
+        Self::from_uint(value, 16)
+    }
 
-        let _ = self.set(self.length - 1, value);
+    /// Create BoolVec holding the 32 bits of `value`, most significant bit first
+    /// ```rust
+    /// use bool_vec::BoolVec;
+    ///
+    /// assert_eq!(BoolVec::from_u32(0xdead_beef).as_bytes(), [0xde, 0xad, 0xbe, 0xef]);
+    /// ```
+    pub fn from_u32(value: u32) -> Self {
+        Self::from_uint(value, 32)
     }
 
-    /// Removes the last element from a BoolVec and returns it, or None if it is empty
+    /// Create BoolVec holding the 64 bits of `value`, most significant bit first
     /// ```rust
-    /// use bool_vec::boolvec;
+    /// use bool_vec::BoolVec;
     ///
-    /// let mut bv = boolvec![true; 8];
+    /// let bv = BoolVec::from_u64(1);
     ///
-    /// assert_eq!(bv.capacity(), 8);
-    /// assert_eq!(bv.len(), 8);
+    /// assert_eq!(bv.len(), 64);
+    /// assert_eq!(bv.get(63), Some(true));
+    /// ```
+    pub fn from_u64(value: u64) -> Self {
+        Self::from_uint(value, 64)
+    }
+
+    /// Create BoolVec holding the 128 bits of `value`, most significant bit first
+    /// ```rust
+    /// use bool_vec::BoolVec;
     ///
-    /// assert_eq!(bv.pop(), Some(true));
+    /// let bv = BoolVec::from_u128(u128::MAX);
     ///
-    /// assert_eq!(bv.capacity(), 8);
-    /// assert_eq!(bv.len(), 7);
+    /// assert_eq!(bv.len(), 128);
+    /// assert_eq!(bv.count_zeros(), 0);
     /// ```
-    /// ---
+    pub fn from_u128(value: u128) -> Self {
+        Self::from_uint(value, 128)
+    }
+
+    /// Create BoolVec holding the bits of `value` as `len` values, the first value being the most significant bit,
+    /// padded with leading false values. This is the inverse of BoolSlice::to_biguint().
+    /// Only available with the `num-bigint` feature.
+    ///
+    /// Returns None if `value` doesn't fit in `len` bits.
     /// ```rust
-    /// use bool_vec::boolvec;
-    /// let mut bv1 = boolvec![true];
-    /// let bv2 = boolvec![];
+    /// use bool_vec::{boolvec, BoolVec};
+    /// use num_bigint::BigUint;
     ///
-    /// bv1.pop();
+    /// let value = BigUint::from(5u8);
     ///
-    /// assert_eq!(bv1, bv2);
+    /// assert_eq!(BoolVec::from_biguint(&value, 5), Some(boolvec![false, false, true, false, true]));
+    /// assert_eq!(BoolVec::from_biguint(&value, 2), None);
     /// ```
-    pub fn pop(&mut self) -> Option<bool> {
-        // self.get()'s check cannot be trusted here since it would require 0u8-1
-        if self.length == 0 {
+    #[cfg(feature = "num-bigint")]
+    pub fn from_biguint(value: &num_bigint::BigUint, len: usize) -> Option<Self> {
+        if value.bits() > len as u64 {
             return None;
         }
 
-        let to_return = self.get(self.length - 1);
+        let byte_len = bytes_for(len);
 
-        // This self.set() is necessary for PartialEq to keep working properly after a pop() operation
-        self.set(self.length - 1, false)?;
+        // The value is moved to the highest bits, so that the first byte starts with the first value
+        let bytes = (value << (byte_len * 8 - len)).to_bytes_be();
 
-        // The previous methods need this attribe to stay unchanged
-        self.length -= 1;
+        // Zero is always converted to a single byte, which isn't needed when `len` is zero too
+        let bytes = &bytes[bytes.len().saturating_sub(byte_len)..];
 
-        to_return
+        let mut bool_vec = Self::with_capacity(len);
+        bool_vec.raw_bytes_mut()[byte_len - bytes.len()..byte_len].copy_from_slice(bytes);
+        bool_vec.length = len;
+
+        Some(bool_vec)
     }
 
-    /// Returns vector capacity (how many booleans the BoolVec can hold without reallocating).
-    /// Capacity will always be a power of 8 since a single boolean takes 1 bit of space and you
-    /// can only allocate a minimum of 1 byte at a time.
-    ///
-    /// For example 8 booleans here take a single byte of space.
+    /// Create BoolVec holding the first `len` values of an Arrow validity bitmap, packed least significant bit first.
+    /// Returns None if `buffer` holds less than `len` values.
+    /// Same as calling BoolVec::from_bytes_with_order() with BitOrder::Lsb0.
     /// ```rust
-    /// use bool_vec::boolvec;
+    /// use bool_vec::{boolvec, BoolVec};
     ///
+    /// let bv = BoolVec::from_arrow_buffer(&[0b1111_0101, 0b0000_0001], 9).unwrap();
     ///
-    /// let bv = boolvec![true; 10];
+    /// assert_eq!(bv, boolvec![true, false, true, false, true, true, true, true, true]);
     ///
-    /// assert_eq!(bv.capacity(), 16);
-    /// assert_eq!(bv.bytes_len(), 2);
+    /// assert!(BoolVec::from_arrow_buffer(&[0b1111_0101], 9).is_none());
     /// ```
-    /// In the example 2 bytes are currently allocated, please read BoolVec::bytes_len()
-    /// documentation for further details.
-    pub fn capacity(&self) -> usize {
-        self.capacity
+    pub fn from_arrow_buffer(buffer: &[u8], len: usize) -> Option<Self> {
+        Self::from_bytes_with_order(buffer, len, BitOrder::Lsb0)
     }
 
-    /// Returns BoolVec's length (the number of booleans in the BoolVec)
+    /// Create BoolVec holding the first `bit_len` values of a string of hexadecimal digits,
+    /// each one holding 4 values most significant bit first, just like the bytes printed by LowerHex.
+    ///
+    /// The string may start with a `0x` prefix, and digits may be separated with underscores or spaces.
+    /// Returns a BoolVecError::InvalidCharacter if any other character is found,
+    /// or a BoolVecError::NotEnoughValues if the digits hold less than `bit_len` values.
+    /// Bits coming after `bit_len` are cleared
     /// ```rust
-    /// use bool_vec::boolvec;
+    /// use bool_vec::{boolvec, BoolVec, BoolVecError};
     ///
+    /// let bv = BoolVec::from_hex_str("0xb_C", 7).unwrap();
     ///
-    /// let bv = boolvec![true; 10];
+    /// assert_eq!(bv, boolvec![true, false, true, true, true, true, false]);
+    /// assert_eq!(BoolVec::from_hex_str("deadbeef", 32).unwrap().count_ones(), 24);
     ///
-    /// assert_eq!(bv.len(), 10);
+    /// assert_eq!(
+    ///     BoolVec::from_hex_str("bc", 9),
+    ///     Err(BoolVecError::NotEnoughValues { bit_len: 9, len: 8 })
+    /// );
+    /// assert_eq!(
+    ///     BoolVec::from_hex_str("bg", 8),
+    ///     Err(BoolVecError::InvalidCharacter { character: 'g', index: 1 })
+    /// );
     /// ```
-    pub fn len(&self) -> usize {
-        self.length
+    pub fn from_hex_str(s: &str, bit_len: usize) -> Result<Self, BoolVecError> {
+        let (offset, digits) = match s.strip_prefix("0x") {
+            Some(digits) => (2, digits),
+            None => (0, s),
+        };
+
+        let byte_len = bytes_for(bit_len);
+
+        let mut bool_vec = Self::with_capacity(bit_len);
+        let bytes = &mut bool_vec.raw_bytes_mut()[..byte_len];
+
+        let mut digit_count = 0;
+
+        for (index, character) in digits.char_indices() {
+            if matches!(character, '_' | ' ') {
+                continue;
+            }
+
+            let Some(digit) = character.to_digit(16) else {
+                return Err(BoolVecError::InvalidCharacter {
+                    character,
+                    index: index + offset,
+                });
+            };
+
+            // Even digits fill the high half of each byte
+            if let Some(byte) = bytes.get_mut(digit_count / 2) {
+                *byte |= (digit as u8) << (4 - digit_count % 2 * 4);
+            }
+
+            digit_count += 1;
+        }
+
+        if digit_count * 4 < bit_len {
+            return Err(BoolVecError::NotEnoughValues {
+                bit_len,
+                len: digit_count * 4,
+            });
+        }
+
+        bool_vec.length = bit_len;
+        bool_vec.clear_unused_bits();
+
+        Ok(bool_vec)
+    }
+
+    /// Create BoolVec holding the first `bit_len` values of packed bytes encoded as a base64 string,
+    /// with the standard alphabet and padding, just like BoolSlice::to_base64() encodes them.
+    /// Only available with the `base64` feature.
+    ///
+    /// Returns BoolVecError::InvalidBase64 if `s` isn't valid base64,
+    /// and BoolVecError::NotEnoughValues if it holds less than `bit_len` values.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec, BoolVecError};
+    ///
+    /// let bv = BoolVec::from_base64("sA==", 3).unwrap();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true]);
+    ///
+    /// assert_eq!(
+    ///     BoolVec::from_base64("sA==", 9),
+    ///     Err(BoolVecError::NotEnoughValues { bit_len: 9, len: 8 })
+    /// );
+    /// assert!(matches!(
+    ///     BoolVec::from_base64("s!==", 3),
+    ///     Err(BoolVecError::InvalidBase64(_))
+    /// ));
+    /// ```
+    #[cfg(feature = "base64")]
+    pub fn from_base64(s: &str, bit_len: usize) -> Result<Self, BoolVecError> {
+        use base64::Engine;
+
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(s)
+            .map_err(BoolVecError::InvalidBase64)?;
+
+        let len = bytes.len() * 8;
+
+        Self::from_bytes(bytes, bit_len).ok_or(BoolVecError::NotEnoughValues { bit_len, len })
+    }
+
+    /// Create BoolVec from runs of equal consecutive values, given as `(value, len)` pairs
+    /// like the ones returned by BoolSlice::to_runs(). Whole bytes of each run are filled at once.
+    ///
+    /// Panics if the total length overflows `usize`.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let bv = BoolVec::from_runs([(true, 2), (false, 1), (true, 1)]);
+    ///
+    /// assert_eq!(bv, boolvec![true, true, false, true]);
+    /// ```
+    pub fn from_runs<I: IntoIterator<Item = (bool, u32)>>(runs: I) -> Self {
+        let mut bool_vec = Self::new();
+
+        for (value, len) in runs {
+            let new_len = bool_vec
+                .len()
+                .checked_add(len as usize)
+                .expect("length should not overflow usize");
+
+            bool_vec.resize(new_len, value);
+        }
+
+        bool_vec
+    }
+
+    /// Create BoolVec holding `universe_len` values, where the values whose index is yielded by `indices` are `true`.
+    /// Indexes can come in any order, and can be repeated.
+    ///
+    /// Returns BoolVecError::IndexOutOfBounds if an index isn't lower than `universe_len`.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec, BoolVecError};
+    ///
+    /// assert_eq!(BoolVec::from_indices([3, 0], 4), Ok(boolvec![true, false, false, true]));
+    /// assert_eq!(
+    ///     BoolVec::from_indices(vec![1, 4], 4),
+    ///     Err(BoolVecError::IndexOutOfBounds { index: 4, len: 4 })
+    /// );
+    /// ```
+    pub fn from_indices<I: IntoIterator<Item = usize>>(
+        indices: I,
+        universe_len: usize,
+    ) -> Result<Self, BoolVecError> {
+        let mut bool_vec = Self::with_capacity(universe_len);
+        bool_vec.resize(universe_len, false);
+
+        for index in indices {
+            if index >= universe_len {
+                return Err(BoolVecError::IndexOutOfBounds {
+                    index,
+                    len: universe_len,
+                });
+            }
+
+            bool_vec.set_bit(index, true);
+        }
+
+        Ok(bool_vec)
+    }
+
+    /// Create BoolVec holding `universe_len` values, where the values whose index is in `bitmap` are `true`.
+    /// Only available with the `roaring` feature.
+    ///
+    /// Returns BoolVecError::IndexOutOfBounds if `bitmap` holds an index which isn't lower than `universe_len`.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec, BoolVecError};
+    /// use roaring::RoaringBitmap;
+    ///
+    /// let bitmap = RoaringBitmap::from_iter([0, 2]);
+    ///
+    /// assert_eq!(BoolVec::from_roaring(&bitmap, 4), Ok(boolvec![true, false, true, false]));
+    /// assert_eq!(
+    ///     BoolVec::from_roaring(&bitmap, 2),
+    ///     Err(BoolVecError::IndexOutOfBounds { index: 2, len: 2 })
+    /// );
+    /// ```
+    #[cfg(feature = "roaring")]
+    pub fn from_roaring(
+        bitmap: &roaring::RoaringBitmap,
+        universe_len: usize,
+    ) -> Result<Self, BoolVecError> {
+        if let Some(index) = bitmap.max().filter(|&index| index as usize >= universe_len) {
+            return Err(BoolVecError::IndexOutOfBounds {
+                index: index as usize,
+                len: universe_len,
+            });
+        }
+
+        let mut bool_vec = Self::with_capacity(universe_len);
+        bool_vec.resize(universe_len, false);
+
+        // Every index is at most the maximum checked above
+        for index in bitmap {
+            bool_vec.set_bit(index as usize, true);
+        }
+
+        Ok(bool_vec)
+    }
+
+    /// Create BoolVec holding the binary number whose reflected Gray code is `gray`,
+    /// the first value being the most significant bit. This is the inverse of BoolSlice::to_gray().
+    ///
+    /// Each value of the result is the XOR of the values of `gray` up to the same position,
+    /// which is computed 64 values at a time.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let gray = boolvec![true, true, false, true];
+    ///
+    /// assert_eq!(BoolVec::from_gray(&gray), boolvec![true, false, false, true]);
+    /// ```
+    pub fn from_gray<'a>(gray: impl Into<BoolSlice<'a>>) -> Self {
+        let gray = gray.into();
+        let mut bool_vec = gray.to_owned();
+        let mut parity = false;
+
+        for start in (0..gray.len()).step_by(64) {
+            let end = (start + 64).min(gray.len());
+            let mut chunk = gray.get_bits(start..end);
+
+            // Each bit becomes the XOR of itself and every bit before it inside the chunk
+            for shift in [1, 2, 4, 8, 16, 32] {
+                chunk ^= chunk >> shift;
+            }
+
+            if parity {
+                chunk = !chunk;
+            }
+
+            parity = chunk & 1 == 1;
+            bool_vec.set_bits(start..end, chunk);
+        }
+
+        bool_vec
+    }
+
+    /// Create BoolVec holding the first `len` values stored in `words`, adopting their buffer without copying it.
+    /// Returns None if `words` holds less than `len` values.
+    ///
+    /// Words are laid out just like BoolVec::as_raw_slice() returns them for a BitOrder::Msb0 BoolVec,
+    /// which is the bit order of the result. Bits coming after `len` are cleared, and words that aren't needed to hold `len` values are dropped.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let words = vec![u64::to_be(0b101 << 61), u64::MAX];
+    ///
+    /// let bv = BoolVec::from_raw_parts(words, 3).unwrap();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true]);
+    /// assert_eq!(bv.as_raw_slice(), [u64::to_be(0b101 << 61)]);
+    ///
+    /// assert!(BoolVec::from_raw_parts(vec![0; 2], 129).is_none());
+    /// ```
+    pub fn from_raw_parts(mut words: Vec<u64>, len: usize) -> Option<Self> {
+        if words_for(len) > words.len() {
+            return None;
+        }
+
+        // Words past the end must always be zero
+        words.truncate(words_for(len));
+
+        let mut bool_vec = Self {
+            words: Words::from_std_vec(words),
+            length: len,
+            order: BitOrder::Msb0,
+        };
+        bool_vec.clear_unused_bits();
+
+        Some(bool_vec)
+    }
+
+    /// Splits the BoolVec into the words storing its values and its length, without copying them.
+    /// Only BoolVecs stored inline need to allocate a new vector.
+    ///
+    /// Words are always laid out most significant bit first, so that BoolVec::from_raw_parts() can put the BoolVec back together.
+    /// BoolVecs stored in another bit order are converted in place first, just like with BoolVec::set_bit_order().
+    /// ```rust
+    /// use bool_vec::{boolvec, BitOrder, BoolVec};
+    ///
+    /// let bv = boolvec![true; 200];
+    ///
+    /// let (words, len) = bv.into_raw_parts();
+    ///
+    /// assert_eq!(words.len(), 4);
+    /// assert_eq!(len, 200);
+    ///
+    /// assert_eq!(BoolVec::from_raw_parts(words, len), Some(boolvec![true; 200]));
+    ///
+    /// let lsb0 = BoolVec::from_bytes_with_order([0b0000_1101], 4, BitOrder::Lsb0).unwrap();
+    ///
+    /// let (words, len) = lsb0.into_raw_parts();
+    ///
+    /// assert_eq!(words, [u64::to_be(0b1011 << 60)]);
+    /// assert_eq!(BoolVec::from_raw_parts(words, len), Some(boolvec![true, false, true, true]));
+    /// ```
+    pub fn into_raw_parts(mut self) -> (Vec<u64>, usize) {
+        self.set_bit_order(BitOrder::Msb0);
+
+        let mut words = self.words.into_std_vec();
+        words.truncate(words_for(self.length));
+
+        (words, self.length)
+    }
+
+    /// Reads values written by BoolSlice::write_to() from `reader`.
+    ///
+    /// Fails with ErrorKind::InvalidData if the header isn't valid or was written by a newer version,
+    /// and with ErrorKind::UnexpectedEof if the reader ends before every value was read.
+    /// ```rust
+    /// use std::io::ErrorKind;
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let mut file = Vec::new();
+    /// boolvec![true; 1000].write_to(&mut file).unwrap();
+    ///
+    /// assert_eq!(BoolVec::read_from(file.as_slice()).unwrap(), boolvec![true; 1000]);
+    ///
+    /// let error = BoolVec::read_from(&file[..100]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    ///
+    /// let error = BoolVec::read_from(&b"NOPE"[..]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    /// ```
+    pub fn read_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        let invalid_data = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+
+        let mut header = [0; 14];
+        reader.read_exact(&mut header[..4])?;
+
+        if header[..4] != HEADER_MAGIC {
+            return Err(invalid_data("missing BoolVec header"));
+        }
+
+        reader.read_exact(&mut header[4..])?;
+
+        let [_, _, _, _, version, order, len @ ..] = header;
+
+        if version != HEADER_VERSION {
+            return Err(invalid_data("unsupported BoolVec header version"));
+        }
+
+        let order = match order {
+            0 => BitOrder::Msb0,
+            1 => BitOrder::Lsb0,
+            _ => return Err(invalid_data("invalid bit order")),
+        };
+
+        let len = usize::try_from(u64::from_le_bytes(len))
+            .map_err(|_| invalid_data("number of values doesn't fit in a usize"))?;
+
+        let byte_len = bytes_for(len);
+
+        // Bytes are read as they come instead of being allocated upfront,
+        // so that a corrupted number of values can't allocate more than the reader holds
+        let mut bytes = Vec::new();
+        reader.take(byte_len as u64).read_to_end(&mut bytes)?;
+
+        Self::from_bytes_with_order(&bytes, len, order).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("{} bytes can't hold {len} values", bytes.len()),
+            )
+        })
+    }
+}
+
+impl<A: Allocator> BoolVec<A> {
+    /// Create empty BoolVec with len: 0, which will allocate with `alloc` once it runs out of inline storage.
+    ///
+    /// Allocators implement the `Allocator` trait, re-exported from the `allocator-api2` crate
+    /// so that it is available on stable Rust. Arena and bump allocators, like `bumpalo`,
+    /// usually implement it for a reference to the arena.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec, Global};
+    /// let mut bv = BoolVec::new_in(Global);
+    ///
+    /// bv.extend_from_slice(&[true; 200]);
+    ///
+    /// assert_eq!(bv, boolvec![true; 200]);
+    /// ```
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            words: Words::new_in(alloc),
+            length: 0,
+            order: BitOrder::Msb0,
+        }
+    }
+
+    /// Allocate empty BoolVec with specified capacity and len: 0, using `alloc`.
+    /// Nothing is allocated if `capacity` is 128 or less, since those booleans are stored inline
+    /// ```rust
+    /// use bool_vec::{BoolVec, Global};
+    /// let bv = BoolVec::with_capacity_in(200, Global);
+    ///
+    /// assert_eq!(bv.capacity(), 256);
+    /// assert!(bv.is_empty());
+    /// ```
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            words: Words::zeroed_in(words_for(capacity), alloc),
+            length: 0,
+            order: BitOrder::Msb0,
+        }
+    }
+
+    /// Returns a reference to the allocator used by the BoolVec
+    /// ```rust
+    /// use bool_vec::{BoolVec, Global};
+    /// let bv = BoolVec::new_in(Global);
+    ///
+    /// let _: &Global = bv.allocator();
+    /// ```
+    pub fn allocator(&self) -> &A {
+        self.words.allocator()
+    }
+
+    /// Appends a bool to the back of a BoolVec
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 128];
+    ///
+    /// assert_eq!(bv.capacity(), bv.bytes_capacity()*8);
+    /// assert_eq!(bv.len(), 128);
+    /// assert_eq!(bv.bytes_len(), 16);
+    ///
+    /// bv.push(false);
+    ///
+    /// assert_eq!(bv.capacity(), bv.bytes_capacity()*8);
+    /// assert_eq!(bv.len(), 129);
+    /// assert_eq!(bv.bytes_len(), 24);
+    ///
+    /// assert_eq!(bv.get(128), Some(false));
+    /// ```
+    /// ---
+    /// If the BoolVec is full, its byte capacity gets doubled.
+    /// The first 16 bytes are stored inline, so the first allocation holds 32 bytes:
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 128];
+    ///
+    /// assert_eq!(bv.bytes_capacity(), 16);
+    ///
+    /// bv.push(true);
+    ///
+    /// assert_eq!(bv.bytes_capacity(), 32);
+    ///
+    /// for _ in 0..128 {
+    ///     bv.push(true);
+    /// }
+    ///
+    /// assert_eq!(bv.bytes_capacity(), 64);
+    /// ```
+    pub fn push(&mut self, value: bool) {
+        self.grow(self.length + 1);
+        self.length += 1;
+
+        self.set_bit(self.length - 1, value);
+    }
+
+    /// Appends a bool to the back of a BoolVec only if there is enough spare capacity,
+    /// so that it never allocates. Otherwise, `value` is given back as an error.
+    ///
+    /// Just like the nightly `Vec::push_within_capacity()`, this is meant for code
+    /// that must not allocate after reserving enough space upfront.
+    /// ```rust
+    /// use bool_vec::BoolVec;
+    ///
+    /// let mut bv = BoolVec::with_capacity(8);
+    /// let capacity = bv.capacity();
+    ///
+    /// for _ in 0..capacity {
+    ///     assert_eq!(bv.push_within_capacity(true), Ok(()));
+    /// }
+    ///
+    /// assert_eq!(bv.push_within_capacity(false), Err(false));
+    ///
+    /// assert_eq!(bv.len(), capacity);
+    /// assert_eq!(bv.capacity(), capacity);
+    /// ```
+    pub fn push_within_capacity(&mut self, value: bool) -> Result<(), bool> {
+        if self.length >= self.capacity() {
+            return Err(value);
+        }
+
+        // The underlying storage already has capacity for the new byte, if any is needed
+        self.push(value);
+
+        Ok(())
+    }
+
+    /// Removes the last element from a BoolVec and returns it, or None if it is empty
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 8];
+    ///
+    /// assert_eq!(bv.capacity(), 128);
+    /// assert_eq!(bv.len(), 8);
+    ///
+    /// assert_eq!(bv.pop(), Some(true));
+    ///
+    /// assert_eq!(bv.capacity(), 128);
+    /// assert_eq!(bv.len(), 7);
+    /// ```
+    /// ---
+    /// ```rust
+    /// use bool_vec::boolvec;
+    /// let mut bv1 = boolvec![true];
+    /// let bv2 = boolvec![];
+    ///
+    /// bv1.pop();
+    ///
+    /// assert_eq!(bv1, bv2);
+    /// ```
+    pub fn pop(&mut self) -> Option<bool> {
+        // self.get()'s check cannot be trusted here since it would require 0u8-1
+        if self.length == 0 {
+            return None;
+        }
+
+        let to_return = self.get(self.length - 1);
+
+        // Values past the end must stay zero, since growing the BoolVec relies on them
+        self.set_bit(self.length - 1, false);
+
+        // The previous methods need this attribe to stay unchanged
+        self.length -= 1;
+
+        to_return
+    }
+
+    /// Removes and returns the bool at position `index`, shifting all the following values to the left.
+    ///
+    /// Just like `Vec::remove()`, this panics if `index` is out of bounds.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true, true, false, false, true, false, true, true];
+    ///
+    /// assert_eq!(bv.remove(1), false);
+    /// assert_eq!(bv, boolvec![true, true, true, false, false, true, false, true, true]);
+    ///
+    /// assert_eq!(bv.remove(8), true);
+    /// assert_eq!(bv, boolvec![true, true, true, false, false, true, false, true]);
+    /// ```
+    /// ---
+    /// ```rust,should_panic
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true];
+    ///
+    /// bv.remove(3);
+    /// ```
+    pub fn remove(&mut self, int_index: usize) -> bool {
+        let len = self.length;
+        let Some(removed) = self.get(int_index) else {
+            panic!("removal index (is {int_index}) should be < len (is {len})");
+        };
+
+        let index = BoolIndex::from(int_index);
+        let last_byte = (len - 1) / 8;
+
+        // Bytes are shifted most significant bit first, whatever the bit order
+        let order = self.order;
+        let bytes = self.raw_bytes_mut();
+
+        // Bits coming before the removed one inside its byte must stay where they are
+        let keep_mask = !(u8::MAX >> index.bit_index);
+        let byte = order.convert(bytes[index.byte_index]);
+        let mut shifted = (byte & keep_mask) | ((byte << 1) & !keep_mask);
+
+        // Every following byte moves one bit to the left, passing its first bit to the previous byte
+        for byte_index in index.byte_index..last_byte {
+            let next = order.convert(bytes[byte_index + 1]);
+
+            bytes[byte_index] = order.convert(shifted | (next >> 7));
+            shifted = next << 1;
+        }
+
+        bytes[last_byte] = order.convert(shifted);
+
+        self.length -= 1;
+
+        removed
+    }
+
+    /// Removes and returns the bool at position `index`, replacing it with the last value of the BoolVec.
+    ///
+    /// This does not preserve ordering, but it's O(1). If you need to preserve the ordering
+    /// please use BoolVec::remove().
+    ///
+    /// Just like `Vec::swap_remove()`, this panics if `index` is out of bounds.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, false, true, false];
+    ///
+    /// assert_eq!(bv.swap_remove(0), true);
+    /// assert_eq!(bv, boolvec![false, false, false, true]);
+    ///
+    /// assert_eq!(bv.swap_remove(3), true);
+    /// assert_eq!(bv, boolvec![false, false, false]);
+    /// ```
+    /// ---
+    /// ```rust,should_panic
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true];
+    ///
+    /// bv.swap_remove(3);
+    /// ```
+    pub fn swap_remove(&mut self, int_index: usize) -> bool {
+        let len = self.length;
+        let Some(removed) = self.get(int_index) else {
+            panic!("swap_remove index (is {int_index}) should be < len (is {len})");
+        };
+
+        // pop() already takes care of clearing the last bit
+        let last = self.pop().unwrap();
+        let _ = self.set(int_index, last);
+
+        removed
+    }
+
+    /// Shortens the BoolVec, keeping the first `len` values and dropping the rest.
+    /// If `len` is greater or equal to BoolVec.len(), this has no effect.
+    ///
+    /// Just like with `Vec`, this has no effect on the capacity of the BoolVec.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 200];
+    ///
+    /// bv.truncate(3);
+    ///
+    /// assert_eq!(bv, boolvec![true; 3]);
+    /// assert_eq!(bv.capacity(), 256);
+    /// assert_eq!(format!("{bv:b}"), "[11100000]");
+    ///
+    /// bv.truncate(5);
+    ///
+    /// assert_eq!(bv.len(), 3);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.length {
+            return;
+        }
+
+        let index = BoolIndex::from(len);
+
+        // Removed values must be zeroed, since growing the BoolVec relies on them
+        let kept = self.order.convert(!(u8::MAX >> index.bit_index));
+        let bytes = self.raw_bytes_mut();
+        bytes[index.byte_index] &= kept;
+        bytes[index.byte_index + 1..].fill(0);
+
+        self.length = len;
+    }
+
+    /// Removes all values from the BoolVec, without releasing the allocated memory.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 200];
+    ///
+    /// bv.clear();
+    ///
+    /// assert!(bv.is_empty());
+    /// assert_eq!(bv.capacity(), 256);
+    ///
+    /// bv.push(false);
+    ///
+    /// assert_eq!(bv, boolvec![false]);
+    /// ```
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Retains only the values for which `f(index, value)` returns true, removing all the others.
+    /// Retained values keep their order and get compacted in place, a whole byte at a time.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true, true, false, false, true, false, true, true];
+    ///
+    /// // Only keep true values and the value at index 1
+    /// bv.retain(|index, value| value || index == 1);
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, true, true, true, true]);
+    /// ```
+    pub fn retain<F: FnMut(usize, bool) -> bool>(&mut self, mut f: F) {
+        let mut kept = 0;
+        let mut byte = 0u8;
+
+        for start in (0..self.length).step_by(8) {
+            // Values are read a whole byte at a time. Retained values are never written past
+            // the byte being read, so the following ones are still intact
+            let read = self.read_byte(start);
+
+            for int_index in start..(start + 8).min(self.length) {
+                let value = read & (0x80 >> (int_index % 8)) != 0;
+
+                if !f(int_index, value) {
+                    continue;
+                }
+
+                if value {
+                    byte |= 1 << (7 - kept % 8);
+                }
+                kept += 1;
+
+                if kept % 8 == 0 {
+                    self.raw_bytes_mut()[kept / 8 - 1] = self.order.convert(byte);
+                    byte = 0;
+                }
+            }
+        }
+
+        if kept % 8 != 0 {
+            self.raw_bytes_mut()[kept / 8] = self.order.convert(byte);
+        }
+
+        // Removed values must be zeroed, since growing the BoolVec relies on them
+        self.raw_bytes_mut()[bytes_for(kept)..].fill(0);
+        self.length = kept;
+    }
+
+    /// Removes consecutive repeated values, collapsing every run of equal values into a single one.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, true, false, false, false, true, false, false];
+    ///
+    /// bv.dedup();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, false]);
+    /// ```
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes all but the first of consecutive values that resolve to the same key.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, true, false, false, true];
+    ///
+    /// // Every value resolves to the same key, so only the first one is kept
+    /// bv.dedup_by_key(|_| 0);
+    ///
+    /// assert_eq!(bv, boolvec![true]);
+    /// ```
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(bool) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes all but the first of consecutive values satisfying the given equality relation.
+    ///
+    /// Just like with `Vec::dedup_by()`, `same_bucket` is passed the current value first
+    /// and the last retained value second. If it returns true, the current value is removed.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false, true, true, false, false, true];
+    ///
+    /// // Remove every true value coming right after a retained false value
+    /// bv.dedup_by(|current, previous| current && !previous);
+    ///
+    /// assert_eq!(bv, boolvec![false, false, false]);
+    /// ```
+    pub fn dedup_by<F: FnMut(bool, bool) -> bool>(&mut self, mut same_bucket: F) {
+        let mut previous = None;
+
+        self.retain(|_, value| match previous {
+            Some(previous) if same_bucket(value, previous) => false,
+            _ => {
+                previous = Some(value);
+                true
+            }
+        });
+    }
+
+    /// Resizes the BoolVec so that its length is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than BoolVec.len(), the BoolVec is extended by the difference,
+    /// with each additional slot filled with `value`.
+    /// If `new_len` is less than BoolVec.len(), the BoolVec is simply truncated.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false, true];
+    ///
+    /// bv.resize(20, true);
+    ///
+    /// assert_eq!(bv.len(), 20);
+    /// assert_eq!(bv.get(0), Some(false));
+    /// assert_eq!(bv.get(19), Some(true));
+    ///
+    /// bv.resize(1, true);
+    ///
+    /// assert_eq!(bv, boolvec![false]);
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: bool) {
+        if new_len <= self.length {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.grow(new_len);
+
+        let old_len = self.length;
+        self.length = new_len;
+
+        // New values are already zeroed, so only true values need to be written
+        if value {
+            self.fill_bits(old_len, new_len, true);
+        }
+    }
+
+    /// Sets the value at `int_index` to `value`.
+    /// If `int_index` is past the end, the BoolVec is first extended up to it,
+    /// with each additional slot filled with `false`.
+    ///
+    /// Panics if the new length overflows `usize`.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, true];
+    ///
+    /// bv.set_or_grow(5, true);
+    ///
+    /// assert_eq!(bv, boolvec![true, true, false, false, false, true]);
+    ///
+    /// bv.set_or_grow(0, false);
+    ///
+    /// assert_eq!(bv, boolvec![false, true, false, false, false, true]);
+    /// ```
+    pub fn set_or_grow(&mut self, int_index: usize, value: bool) {
+        if int_index >= self.length {
+            let new_len = int_index.checked_add(1).expect("capacity overflow");
+
+            // New values are already zeroed, so the gap is filled with false
+            self.grow(new_len);
+            self.length = new_len;
+        }
+
+        self.set_bit(int_index, value);
+    }
+
+    /// Inserts all the values of `values` at position `int_index`,
+    /// shifting the values after it to the right only once.
+    ///
+    /// Just like `Vec::insert()`, this panics if `int_index > len`.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, true, true];
+    ///
+    /// bv.insert_slice(1, &[false, false]);
+    ///
+    /// assert_eq!(bv, boolvec![true, false, false, true, true]);
+    ///
+    /// bv.insert_slice(5, &[false; 10]);
+    ///
+    /// assert_eq!(bv.len(), 15);
+    /// assert_eq!(bv.count_ones(), 3);
+    /// ```
+    pub fn insert_slice(&mut self, int_index: usize, values: &[bool]) {
+        let old_len = self.length;
+        if int_index > old_len {
+            panic!("insertion index (is {int_index}) should be <= len (is {old_len})");
+        }
+
+        let new_len = old_len
+            .checked_add(values.len())
+            .expect("capacity overflow");
+
+        self.grow(new_len);
+        self.length = new_len;
+
+        // Make room for the new values by moving the tail a whole byte at a time
+        self.copy_within(int_index..old_len, int_index + values.len());
+
+        let mut slice = self.as_mut_slice();
+
+        for (i, chunk) in values.chunks(8).enumerate() {
+            slice.write_byte(int_index + i * 8, pack_bools(chunk), chunk.len());
+        }
+    }
+
+    /// Moves all the values of `other` into `self`, leaving `other` empty.
+    ///
+    /// Values are copied a whole byte at a time, even if BoolVec.len() is not a multiple of 8.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv1 = boolvec![true, false, true];
+    /// let mut bv2 = boolvec![false, false, true, true, false, true, true, true, false];
+    ///
+    /// bv1.append(&mut bv2);
+    ///
+    /// assert_eq!(bv1, boolvec![true, false, true, false, false, true, true, false, true, true, true, false]);
+    /// assert!(bv2.is_empty());
+    /// ```
+    pub fn append<B: Allocator>(&mut self, other: &mut BoolVec<B>) {
+        self.append_bytes(other.raw_bytes(), other.length, other.order);
+        other.clear();
+    }
+
+    /// Appends all the values of `other` to the back of the BoolVec, leaving `other` untouched.
+    ///
+    /// Values are copied a whole byte at a time, even if BoolVec.len() is not a multiple of 8.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv1 = boolvec![true, false, true];
+    /// let bv2 = boolvec![false, true];
+    ///
+    /// bv1.extend_from_boolvec(&bv2);
+    ///
+    /// assert_eq!(bv1, boolvec![true, false, true, false, true]);
+    /// assert_eq!(bv2, boolvec![false, true]);
+    /// ```
+    pub fn extend_from_boolvec<B: Allocator>(&mut self, other: &BoolVec<B>) {
+        self.append_bytes(other.raw_bytes(), other.length, other.order);
+    }
+
+    /// Appends all the values of a slice of bools to the back of the BoolVec.
+    ///
+    /// Values are packed 8 at a time and written a whole byte at a time,
+    /// instead of being pushed one by one.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true];
+    ///
+    /// bv.extend_from_slice(&[false, true]);
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, false, true]);
+    /// ```
+    pub fn extend_from_slice(&mut self, values: &[bool]) {
+        let old_len = self.length;
+        let new_len = old_len
+            .checked_add(values.len())
+            .expect("capacity overflow");
+
+        self.grow(new_len);
+        self.length = new_len;
+
+        let mut slice = self.as_mut_slice();
+
+        for (i, chunk) in values.chunks(8).enumerate() {
+            slice.write_byte(old_len + i * 8, pack_bools(chunk), chunk.len());
+        }
+    }
+
+    /// Removes the specified range from the BoolVec, returning all the removed values as an iterator.
+    /// The remaining values get compacted once the iterator is dropped,
+    /// even if it was not fully consumed.
+    ///
+    /// Just like `Vec::drain()`, this panics if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the BoolVec.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, false, true, true, false, true, true, false, true];
+    ///
+    /// let drained: Vec<bool> = bv.drain(2..5).collect();
+    ///
+    /// assert_eq!(drained, vec![false, true, true]);
+    /// assert_eq!(bv, boolvec![true, false, false, true, true, false, true]);
+    ///
+    /// bv.drain(..);
+    ///
+    /// assert!(bv.is_empty());
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, A> {
+        let (start, end) = range_to_indexes(range, self.length);
+
+        Drain {
+            vec: self,
+            start,
+            counter: start,
+            back: end,
+            end,
+        }
+    }
+
+    /// Replaces the specified range in the BoolVec with the values yielded by `replace_with`,
+    /// returning all the removed values as an iterator.
+    /// `replace_with` does not need to have the same length as the range.
+    ///
+    /// The replacement happens once the returned iterator is dropped,
+    /// even if it was not fully consumed.
+    ///
+    /// Just like `Vec::splice()`, this panics if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the BoolVec.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, false, true, true];
+    ///
+    /// let removed: Vec<bool> = bv.splice(1..3, [true, true, true, false]).collect();
+    ///
+    /// assert_eq!(removed, vec![false, false]);
+    /// assert_eq!(bv, boolvec![true, true, true, true, false, true, true]);
+    /// ```
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, A>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = bool>,
+    {
+        Splice {
+            drain: self.drain(range),
+            replace_with: replace_with.into_iter(),
+        }
+    }
+
+    /// Returns vector capacity (how many booleans the BoolVec can hold without reallocating).
+    /// Capacity will always be a multiple of 64 since a single boolean takes 1 bit of space and
+    /// booleans are stored 64 at a time, in words of 8 bytes.
+    /// Up to 2 words are stored inline, so capacity is never less than 128.
+    ///
+    /// For example 200 booleans here take 4 words of space.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    ///
+    /// let bv = boolvec![true; 200];
+    ///
+    /// assert_eq!(bv.capacity(), 256);
+    /// assert_eq!(bv.bytes_len(), 32);
+    /// ```
+    /// In the example 32 bytes are currently allocated, please read BoolVec::bytes_len()
+    /// documentation for further details.
+    pub fn capacity(&self) -> usize {
+        self.words.capacity() * 64
+    }
+
+    /// Reserves capacity for at least `additional` more booleans to be inserted in the BoolVec.
+    /// Just like with `Vec`, more space might be reserved to avoid frequent reallocations.
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true];
+    ///
+    /// bv.reserve(20);
+    ///
+    /// assert!(bv.capacity() >= 21);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        let additional_words = self
+            .additional_words(additional)
+            .expect("capacity overflow");
+
+        self.words.reserve(additional_words);
+    }
+
+    /// Reserves the minimum capacity for at least `additional` more booleans to be inserted in the BoolVec.
+    /// Capacity will still be a multiple of 64, check BoolVec::capacity() documentation for more infos.
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true];
+    ///
+    /// bv.reserve_exact(100);
+    ///
+    /// assert_eq!(bv.capacity(), 128);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let additional_words = self
+            .additional_words(additional)
+            .expect("capacity overflow");
+
+        self.words.reserve_exact(additional_words);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more booleans to be inserted in the BoolVec.
+    /// Just like with `Vec`, more space might be reserved to avoid frequent reallocations.
+    ///
+    /// Returns an error instead of panicking or aborting if the capacity overflows
+    /// or if the allocator reports a failure.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true];
+    ///
+    /// assert!(bv.try_reserve(20).is_ok());
+    /// assert!(bv.capacity() >= 21);
+    ///
+    /// assert!(bv.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        // Asking for usize::MAX words makes the underlying storage report the overflow
+        let additional_words = self.additional_words(additional).unwrap_or(usize::MAX);
+
+        self.words.try_reserve(additional_words)?;
+
+        Ok(())
+    }
+
+    /// Tries to reserve the minimum capacity for at least `additional` more booleans
+    /// to be inserted in the BoolVec.
+    /// Capacity will still be a multiple of 64, check BoolVec::capacity() documentation for more infos.
+    ///
+    /// Returns an error instead of panicking or aborting if the capacity overflows
+    /// or if the allocator reports a failure.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true];
+    ///
+    /// assert!(bv.try_reserve_exact(100).is_ok());
+    /// assert_eq!(bv.capacity(), 128);
+    ///
+    /// assert!(bv.try_reserve_exact(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        // Asking for usize::MAX words makes the underlying storage report the overflow
+        let additional_words = self.additional_words(additional).unwrap_or(usize::MAX);
+
+        self.words.try_reserve_exact(additional_words)?;
+
+        Ok(())
+    }
+
+    /// Shrinks the capacity of the BoolVec as much as possible.
+    /// ```rust
+    /// use bool_vec::BoolVec;
+    ///
+    /// let mut bv = BoolVec::with_capacity(1000);
+    /// bv.push(true);
+    ///
+    /// bv.shrink_to_fit();
+    ///
+    /// // Few enough values are moved back inline
+    /// assert_eq!(bv.capacity(), 128);
+    /// assert!(bv.is_inline());
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.words.shrink(words_for(self.length), 0);
+    }
+
+    /// Shrinks the capacity of the BoolVec with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both the length and the supplied value.
+    /// If the current capacity is less than the lower limit, this has no effect.
+    /// ```rust
+    /// use bool_vec::BoolVec;
+    ///
+    /// let mut bv = BoolVec::with_capacity(1000);
+    /// bv.push(true);
+    ///
+    /// bv.shrink_to(200);
+    /// assert_eq!(bv.capacity(), 256);
+    ///
+    /// bv.shrink_to(0);
+    /// assert_eq!(bv.capacity(), 128);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        // Words past the end are always zero, so they can be dropped without losing anything
+        let word_len = words_for(self.length).max(words_for(min_capacity));
+
+        self.words.shrink(word_len, words_for(min_capacity));
+    }
+
+    /// Returns BoolVec's length (the number of booleans in the BoolVec)
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    ///
+    /// let bv = boolvec![true; 10];
+    ///
+    /// assert_eq!(bv.len(), 10);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.length
     }
 
     /// Returns true if the BoolVec contains no booleans
     /// ```rust
     /// use bool_vec::boolvec;
     ///
-    /// let bv = boolvec![];
+    /// let bv = boolvec![];
+    ///
+    /// assert!(bv.is_empty());
+    /// ```
+    ///
+    /// ---
+    ///
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true];
+    /// bv.pop();
+    ///
+    /// assert!(bv.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns true if the values are stored inline, without any heap allocation.
+    ///
+    /// Up to 128 values are stored inline. Longer BoolVecs move their values to the heap,
+    /// and only move them back inline when shrunk with BoolVec::shrink_to_fit() or BoolVec::shrink_to().
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 128];
+    ///
+    /// assert!(bv.is_inline());
+    ///
+    /// bv.push(false);
+    ///
+    /// assert!(!bv.is_inline());
+    ///
+    /// bv.truncate(10);
+    /// bv.shrink_to_fit();
+    ///
+    /// assert!(bv.is_inline());
+    /// assert_eq!(bv, boolvec![true; 10]);
+    /// ```
+    pub fn is_inline(&self) -> bool {
+        self.words.is_inline()
+    }
+
+    /// Returns the capacity of the underlying storage that stores the values.
+    /// This function is here just for testing purposes
+    ///
+    /// This capacity is expressed in bytes, while the normal capacity of BoolVec is expressed in bits.
+    /// Since values are stored in words of 8 bytes, it is always a multiple of 8.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 8];
+    /// bv.push(true);
+    ///
+    /// assert_eq!(bv.capacity(), bv.bytes_capacity()*8);
+    /// ```
+    pub fn bytes_capacity(&self) -> usize {
+        self.words.capacity() * 8
+    }
+
+    /// Returns the number of elements of the underlying storage that stores the values.
+    /// This function is here just for testing purposes
+    ///
+    /// This length is expressed in bytes, while the normal length of BoolVec is expressed in bits.
+    /// Since values are stored in words of 8 bytes, it is always a multiple of 8.
+    /// Inline storage always counts as 16 bytes.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 128];
+    ///
+    /// assert_eq!(bv.len(), 128);
+    /// assert_eq!(bv.bytes_len(), 16);
+    ///
+    /// bv.push(true);
+    ///
+    /// assert_eq!(bv.len(), 129);
+    /// assert_eq!(bv.bytes_len(), 24);
+    /// ```
+    pub fn bytes_len(&self) -> usize {
+        self.words.len() * 8
+    }
+
+    /// Copies BoolVec data into a `Vec<bool>`
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// let vector = bv.into_vec();
+    ///
+    /// assert_eq!(vector, vec![true, false, true]);
+    /// ```
+    ///
+    /// To achieve the opposite (getting a `BoolVec` from a `Vec<bool>` or a `&[bool]`),
+    /// please see BoolVec::from()
+    ///
+    /// *WARNING: This might require a non indifferent amount of memory if you are working
+    /// with a large amount of data, please consider trying to work with BoolVec directly
+    /// if you can*
+    pub fn into_vec(&self) -> Vec<bool> {
+        let mut new_vec = Vec::with_capacity(self.len());
+
+        for b in self {
+            new_vec.push(b);
+        }
+
+        new_vec
+    }
+
+    /// Returns a BoolSlice viewing all the values of the BoolVec, check BoolSlice docs for more infos
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// let slice = bv.as_slice();
+    ///
+    /// assert_eq!(slice.len(), 3);
+    /// assert_eq!(slice.get(2), Some(true));
+    /// ```
+    pub fn as_slice(&self) -> BoolSlice<'_> {
+        let ptr = NonNull::from(&*self.words).cast();
+
+        // SAFETY: the underlying storage always holds at least bytes_for(self.length) bytes,
+        // and the pointer comes from a borrow of all of them
+        unsafe { BoolSlice::from_raw_parts(ptr, 0, self.length, self.order) }
+    }
+
+    /// Returns a BoolSliceMut viewing all the values of the BoolVec, check BoolSliceMut docs for more infos
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true];
+    ///
+    /// bv.as_mut_slice().fill(false);
+    ///
+    /// assert_eq!(bv, boolvec![false; 3]);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> BoolSliceMut<'_> {
+        let ptr = NonNull::from(&mut *self.words).cast();
+
+        // SAFETY: the underlying storage always holds at least bytes_for(self.length) bytes,
+        // and the pointer comes from a mutable borrow of all of them
+        unsafe { BoolSliceMut::from_raw_parts(ptr, 0, self.length, self.order) }
+    }
+
+    /// Changes the order in which the values are packed into bytes, converting the underlying storage in place.
+    /// Values themselves are left untouched, check BitOrder docs for more infos
+    /// ```rust
+    /// use bool_vec::{boolvec, BitOrder};
+    ///
+    /// let mut bv = boolvec![true, false, true, true];
+    ///
+    /// assert_eq!(bv.as_bytes(), [0b1011_0000]);
+    ///
+    /// bv.set_bit_order(BitOrder::Lsb0);
+    ///
+    /// assert_eq!(bv.as_bytes(), [0b0000_1101]);
+    /// assert_eq!(bv, boolvec![true, false, true, true]);
+    /// ```
+    pub fn set_bit_order(&mut self, order: BitOrder) {
+        let from = self.order;
+
+        // Bits past the end are zero in any order, so only the used words are converted
+        for word in self.as_raw_mut_slice() {
+            *word = order.convert_word_from(from, *word);
+        }
+
+        self.order = order;
+    }
+
+    /// Returns the words storing the values of the BoolVec, 64 values per word, without copying them.
+    ///
+    /// Read in memory order, the words are made of bytes holding 8 values each, packed in the bit order of the BoolVec.
+    /// With BitOrder::Msb0, the first value of each byte is its most significant bit,
+    /// so values are laid out just like the bits of big-endian integers:
+    /// value `i` is bit `63 - i % 64` of `u64::from_be(words[i / 64])`.
+    ///
+    /// Only the words needed to hold BoolVec.len() values are returned,
+    /// and bits of the last word coming after BoolVec.len() are always zero.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// let words = bv.as_raw_slice();
+    ///
+    /// assert_eq!(words.len(), 1);
+    /// assert_eq!(u64::from_be(words[0]), 0b101 << 61);
+    /// ```
+    pub fn as_raw_slice(&self) -> &[u64] {
+        &self.words[..words_for(self.length)]
+    }
+
+    /// Returns the words storing the values of the BoolVec mutably, check BoolVec::as_raw_slice() docs for more infos.
+    ///
+    /// Bits of the last word coming after BoolVec.len() must be left to zero,
+    /// since counting values and growing the BoolVec rely on them, among other methods.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false; 100];
+    ///
+    /// bv.as_raw_mut_slice()[0] = u64::MAX;
+    ///
+    /// assert_eq!(bv.count_ones(), 64);
+    /// assert_eq!(bv.get(63), Some(true));
+    /// assert_eq!(bv.get(64), Some(false));
+    /// ```
+    pub fn as_raw_mut_slice(&mut self) -> &mut [u64] {
+        let word_len = words_for(self.length);
+
+        &mut self.words[..word_len]
+    }
+
+    /// Returns the packed bytes storing the values of the BoolVec, without copying them.
+    ///
+    /// Values are packed in the bit order of the BoolVec, most significant bit first unless asked otherwise,
+    /// just like with BoolVec::from_bytes_with_order().
+    /// Only the bytes needed to hold BoolVec.len() values are returned,
+    /// and bits of the last byte coming after BoolVec.len() are always zero.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false, false, false, false, true];
+    ///
+    /// assert_eq!(bv.as_bytes(), [0b1011_0000, 0b1000_0000]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.raw_bytes()[..bytes_for(self.length)]
+    }
+
+    /// Copies the packed bytes storing the values of the BoolVec into a `Vec<u8>`,
+    /// check BoolVec::as_bytes() docs for more infos.
+    ///
+    /// Values are stored in words of 8 bytes, so their buffer can't be reused and gets copied.
+    /// Use BoolVec::into_raw_parts() to move the words without copying them.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![false, true, true];
+    ///
+    /// assert_eq!(bv.into_bytes(), vec![0b0110_0000]);
+    /// ```
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    /// Keeps the values that are `true` in either BoolVec, storing the results in `self`.
+    /// This is the logical OR of the two, computed 64 values at a time.
+    ///
+    /// With LengthPolicy::ZeroExtend the shortest BoolVec is treated as if it was extended with `false` values,
+    /// growing `self` if needed, while LengthPolicy::Exact returns BoolVecError::LengthMismatch
+    /// leaving `self` untouched if the lengths differ.
+    /// ```rust
+    /// use bool_vec::{boolvec, LengthPolicy};
+    ///
+    /// let mut bv = boolvec![true, false, false];
+    /// let other = boolvec![false, true, false, true];
+    ///
+    /// bv.union_with(&other, LengthPolicy::ZeroExtend).unwrap();
+    ///
+    /// assert_eq!(bv, boolvec![true, true, false, true]);
+    /// ```
+    pub fn union_with<B: Allocator>(
+        &mut self,
+        other: &BoolVec<B>,
+        policy: LengthPolicy,
+    ) -> Result<(), BoolVecError> {
+        self.combine_with_policy(other, policy, |a, b| a | b)
+    }
+
+    /// Keeps the values that are `true` in both BoolVecs, storing the results in `self`.
+    /// This is the logical AND of the two, computed 64 values at a time.
+    ///
+    /// With LengthPolicy::ZeroExtend the shortest BoolVec is treated as if it was extended with `false` values,
+    /// growing `self` if needed, while LengthPolicy::Exact returns BoolVecError::LengthMismatch
+    /// leaving `self` untouched if the lengths differ.
+    /// ```rust
+    /// use bool_vec::{boolvec, LengthPolicy};
+    ///
+    /// let mut bv = boolvec![true, true, false, true];
+    /// let other = boolvec![false, true, true];
+    ///
+    /// bv.intersect_with(&other, LengthPolicy::ZeroExtend).unwrap();
+    ///
+    /// assert_eq!(bv, boolvec![false, true, false, false]);
+    /// ```
+    pub fn intersect_with<B: Allocator>(
+        &mut self,
+        other: &BoolVec<B>,
+        policy: LengthPolicy,
+    ) -> Result<(), BoolVecError> {
+        self.combine_with_policy(other, policy, |a, b| a & b)
+    }
+
+    /// Keeps the values that are `true` in `self` but not in `other`, storing the results in `self`.
+    /// This is the logical AND NOT of the two, computed 64 values at a time.
+    ///
+    /// With LengthPolicy::ZeroExtend the shortest BoolVec is treated as if it was extended with `false` values,
+    /// growing `self` if needed, while LengthPolicy::Exact returns BoolVecError::LengthMismatch
+    /// leaving `self` untouched if the lengths differ.
+    /// ```rust
+    /// use bool_vec::{boolvec, LengthPolicy};
+    ///
+    /// let mut bv = boolvec![true, true, false, true];
+    /// let other = boolvec![false, true, true];
+    ///
+    /// bv.difference_with(&other, LengthPolicy::ZeroExtend).unwrap();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, false, true]);
+    /// ```
+    pub fn difference_with<B: Allocator>(
+        &mut self,
+        other: &BoolVec<B>,
+        policy: LengthPolicy,
+    ) -> Result<(), BoolVecError> {
+        self.combine_with_policy(other, policy, |a, b| a & !b)
+    }
+
+    /// Keeps the values that are `true` in exactly one of the two BoolVecs, storing the results in `self`.
+    /// This is the logical XOR of the two, computed 64 values at a time.
+    ///
+    /// With LengthPolicy::ZeroExtend the shortest BoolVec is treated as if it was extended with `false` values,
+    /// growing `self` if needed, while LengthPolicy::Exact returns BoolVecError::LengthMismatch
+    /// leaving `self` untouched if the lengths differ.
+    /// ```rust
+    /// use bool_vec::{boolvec, LengthPolicy};
+    ///
+    /// let mut bv = boolvec![true, true, false];
+    /// let other = boolvec![false, true, true, true];
+    ///
+    /// bv.symmetric_difference_with(&other, LengthPolicy::ZeroExtend).unwrap();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, true]);
+    /// ```
+    pub fn symmetric_difference_with<B: Allocator>(
+        &mut self,
+        other: &BoolVec<B>,
+        policy: LengthPolicy,
+    ) -> Result<(), BoolVecError> {
+        self.combine_with_policy(other, policy, |a, b| a ^ b)
+    }
+
+    /// Combines the words of `self` and `other` with `op`, storing the results in `self`.
+    /// The shortest of the two is extended with zeros, unless `policy` requires them to have the same length
+    fn combine_with_policy<B, F>(
+        &mut self,
+        other: &BoolVec<B>,
+        policy: LengthPolicy,
+        op: F,
+    ) -> Result<(), BoolVecError>
+    where
+        B: Allocator,
+        F: Fn(u64, u64) -> u64 + Sync,
+    {
+        if policy == LengthPolicy::Exact && self.length != other.length {
+            return Err(BoolVecError::LengthMismatch {
+                len: self.length,
+                other_len: other.length,
+            });
+        }
+
+        if other.length > self.length {
+            self.resize(other.length, false);
+        }
+
+        // Values past the end of `other` are combined with zeros,
+        // which only changes them if `op` clears them
+        if op(u64::MAX, 0) == 0 {
+            self.fill_bits(other.length, self.length, false);
+        }
+
+        self.combine_in_place(other, op);
+
+        Ok(())
+    }
+
+    /// Returns the packed bytes storing the values most significant bit first, like serialization formats store them.
+    /// Only the bytes of BoolVecs stored in another bit order get copied
+    #[cfg(any(
+        feature = "bit-vec",
+        feature = "borsh",
+        feature = "rkyv",
+        feature = "serde"
+    ))]
+    fn msb0_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        match self.order {
+            BitOrder::Msb0 => std::borrow::Cow::Borrowed(self.as_bytes()),
+            BitOrder::Lsb0 => std::borrow::Cow::Owned(self.as_slice().bytes().collect()),
+        }
+    }
+
+    /// Returns the bytes of the underlying storage, in memory order
+    fn raw_bytes(&self) -> &[u8] {
+        // SAFETY: u8 has no alignment requirement and every byte of a u64 is initialized
+        unsafe { std::slice::from_raw_parts(self.words.as_ptr().cast(), self.words.len() * 8) }
+    }
+
+    /// Returns the bytes of the underlying storage mutably, in memory order
+    fn raw_bytes_mut(&mut self) -> &mut [u8] {
+        // SAFETY: u8 has no alignment requirement and any sequence of bytes is a valid u64
+        unsafe {
+            std::slice::from_raw_parts_mut(self.words.as_mut_ptr().cast(), self.words.len() * 8)
+        }
+    }
+
+    /// Sets the value at `int_index`, which must be lower than BoolVec.len(), writing the byte directly.
+    /// Since the whole BoolVec is borrowed mutably, no atomic access is needed
+    fn set_bit(&mut self, int_index: usize, value: bool) {
+        let index = BoolIndex::from(int_index);
+        let bit = self.order.convert(1 << (7 - index.bit_index));
+
+        let byte = &mut self.raw_bytes_mut()[index.byte_index];
+
+        if value {
+            // Assign one to that single bit
+            *byte |= bit;
+        } else {
+            // Assign zero to that single bit
+            *byte &= !bit;
+        }
+    }
+
+    /// Returns how many words must be reserved on top of the ones already held
+    /// by the underlying storage to fit `additional` more values.
+    /// Returns None if the resulting length overflows `usize`
+    fn additional_words(&self, additional: usize) -> Option<usize> {
+        let len = self.length.checked_add(additional)?;
+
+        Some(words_for(len).saturating_sub(self.words.len()))
+    }
+
+    /// Makes sure the underlying storage holds enough words for `len` values.
+    ///
+    /// When reallocating, the capacity of the underlying storage is at least doubled,
+    /// so that pushing values one at a time only reallocates a logarithmic number of times.
+    fn grow(&mut self, len: usize) {
+        let word_len = words_for(len);
+
+        if word_len <= self.words.len() {
+            return;
+        }
+
+        let word_capacity = self.words.capacity();
+
+        if word_len > word_capacity {
+            let new_capacity = word_len.max(word_capacity * 2);
+
+            self.words.reserve_exact(new_capacity - self.words.len());
+        }
+
+        self.words.extend_zeroed(word_len);
+    }
+
+    /// Reads the 8 values starting at `start` as a single byte most significant bit first, even when `start` is not
+    /// a multiple of 8. Bits past the end of the underlying storage are read as zero
+    fn read_byte(&self, start: usize) -> u8 {
+        let index = BoolIndex::from(start);
+        let load = |byte_index| {
+            let byte = self.raw_bytes().get(byte_index).copied().unwrap_or(0);
+
+            self.order.convert(byte)
+        };
+
+        let high = load(index.byte_index);
+
+        if index.bit_index == 0 {
+            return high;
+        }
+
+        let low = load(index.byte_index + 1);
+
+        (high << index.bit_index) | (low >> (8 - index.bit_index))
+    }
+
+    /// Removes the values from `start` (included) to `end` (excluded),
+    /// moving all the following values back a whole byte at a time
+    fn remove_bits(&mut self, start: usize, end: usize) {
+        if start >= end {
+            return;
+        }
+
+        let len = self.length;
+        let moved = len - end;
+
+        let mut src = end;
+        let mut dst = start;
+
+        // The first destination byte might be only partially overwritten
+        let head = BoolIndex::from(dst);
+        if head.bit_index != 0 && moved > 0 {
+            let mask = self.order.convert(u8::MAX >> head.bit_index);
+            let value = self.order.convert(self.read_byte(src) >> head.bit_index);
+
+            let byte = &mut self.raw_bytes_mut()[head.byte_index];
+            *byte = (*byte & !mask) | (value & mask);
+
+            src += 8 - head.bit_index as usize;
+            dst += 8 - head.bit_index as usize;
+        }
+
+        // Sources always come after destinations, so they're read before being overwritten
+        while dst < start + moved {
+            let byte = self.order.convert(self.read_byte(src));
+            self.raw_bytes_mut()[dst / 8] = byte;
+            src += 8;
+            dst += 8;
+        }
+
+        // truncate() takes care of zeroing whatever was left past the new end
+        self.truncate(start + moved);
+    }
+
+    /// Appends the first `len` values stored in `bytes` in `order` to the back of the BoolVec,
+    /// shifting each byte into place when BoolVec.len() is not a multiple of 8
+    fn append_bytes(&mut self, bytes: &[u8], len: usize, order: BitOrder) {
+        if len == 0 {
+            return;
+        }
+
+        let offset = BoolIndex::from(self.length);
+        let last = BoolIndex::from(len - 1);
+
+        self.grow(self.length + len);
+        let own_order = self.order;
+        let own_bytes = self.raw_bytes_mut();
+
+        // Bytes are shifted most significant bit first, then stored in the order of the BoolVec
+        let store = |own_byte: &mut u8, byte: u8| *own_byte |= own_order.convert(byte);
+
+        for (i, &byte) in bytes[..=last.byte_index].iter().enumerate() {
+            let byte = order.convert(byte);
+
+            // Trailing bits of the source are not guaranteed to be zero
+            let byte = if i == last.byte_index {
+                byte & (u8::MAX << (7 - last.bit_index))
+            } else {
+                byte
+            };
+
+            let byte_index = offset.byte_index + i;
+
+            // Values past self.length are always zero, so OR-ing is enough
+            store(&mut own_bytes[byte_index], byte >> offset.bit_index);
+
+            if offset.bit_index != 0 {
+                if let Some(next) = own_bytes.get_mut(byte_index + 1) {
+                    store(next, byte << (8 - offset.bit_index));
+                }
+            }
+        }
+
+        self.length += len;
+    }
+
+    /// Sets every value from `start` (included) to `end` (excluded) to `value`,
+    /// writing whole bytes at a time where possible
+    fn fill_bits(&mut self, start: usize, end: usize, value: bool) {
+        if start >= end {
+            return;
+        }
+
+        let first = BoolIndex::from(start);
+        let last = BoolIndex::from(end - 1);
+
+        // Masks selecting the bits that belong to the range inside the first and the last byte
+        let first_mask = self.order.convert(u8::MAX >> first.bit_index);
+        let last_mask = self.order.convert(u8::MAX << (7 - last.bit_index));
+
+        let apply = |byte: &mut u8, mask: u8| {
+            if value {
+                *byte |= mask;
+            } else {
+                *byte &= !mask;
+            }
+        };
+
+        let bytes = self.raw_bytes_mut();
+
+        if first.byte_index == last.byte_index {
+            apply(&mut bytes[first.byte_index], first_mask & last_mask);
+            return;
+        }
+
+        apply(&mut bytes[first.byte_index], first_mask);
+        fill_bytes(
+            &mut bytes[first.byte_index + 1..last.byte_index],
+            if value { u8::MAX } else { 0 },
+        );
+        apply(&mut bytes[last.byte_index], last_mask);
+    }
+
+    /// Returns a word whose first `len` values are set in the bit order of the BoolVec,
+    /// `len` being lower than 64
+    fn word_mask(&self, len: usize) -> u64 {
+        self.order.convert_word(word_mask(len))
+    }
+
+    /// Returns a word of the underlying storage as a big-endian integer, the first value being the most significant bit
+    fn msb0_word(&self, word: u64) -> u64 {
+        u64::from_be(self.order.convert_word(word))
+    }
+
+    /// Sets to zero the bits of the last used word coming after BoolVec.len()
+    fn clear_unused_bits(&mut self) {
+        let remainder = self.length % 64;
+
+        if remainder != 0 {
+            self.words[self.length / 64] &= self.word_mask(remainder);
+        }
+    }
+
+    /// Combines the words of `self` and `other` with `op`, storing the results in `self`.
+    /// Values of `self` past the end of `other` are left untouched
+    fn combine_in_place<B, F>(&mut self, other: &BoolVec<B>, op: F)
+    where
+        B: Allocator,
+        F: Fn(u64, u64) -> u64 + Sync,
+    {
+        let len = self.length.min(other.length);
+        let full_words = len / 64;
+
+        // Words of `other` are converted to the bit order of `self` first
+        let (order, other_order) = (self.order, other.order);
+        let op = |a, b| op(a, order.convert_word_from(other_order, b));
+
+        apply_words(
+            &mut self.words[..full_words],
+            &other.words[..full_words],
+            op,
+        );
+
+        // Values of the last word coming after the combined range must be preserved
+        let remainder = len % 64;
+        if remainder != 0 {
+            let mask = self.word_mask(remainder);
+            let word = &mut self.words[full_words];
+            let combined = op(*word, other.words[full_words]);
+
+            *word = (combined & mask) | (*word & !mask);
+        }
+    }
+
+    /// Compares the values of `self` and `other` lexicographically, a whole word at a time.
+    /// Once stored most significant bit first, words compare just like big-endian integers
+    fn cmp_values<B: Allocator>(&self, other: &BoolVec<B>) -> Ordering {
+        let len = self.length.min(other.length);
+        let full_words = len / 64;
+
+        let words = self.words[..full_words]
+            .iter()
+            .zip(&other.words[..full_words]);
+        for (&a, &b) in words {
+            let (a, b) = (self.msb0_word(a), other.msb0_word(b));
+
+            if a != b {
+                return a.cmp(&b);
+            }
+        }
+
+        // Only the values both BoolVecs hold are compared inside the last word
+        let remainder = len % 64;
+        if remainder != 0 {
+            let mask = !(u64::MAX >> remainder);
+            let a = self.msb0_word(self.words[full_words]) & mask;
+            let b = other.msb0_word(other.words[full_words]) & mask;
+
+            if a != b {
+                return a.cmp(&b);
+            }
+        }
+
+        self.length.cmp(&other.length)
+    }
+}
+
+impl<A: Allocator + Clone> BoolVec<A> {
+    /// Splits the BoolVec into two at the given index.
+    ///
+    /// Returns a newly allocated BoolVec containing the values in the range `[at, len)`,
+    /// using a clone of the same allocator.
+    /// After the call, the original BoolVec will be left containing the values `[0, at)`
+    /// with its previous capacity unchanged.
+    ///
+    /// Just like `Vec::split_off()`, this panics if `at > len`.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, true, false, true, false, false, false, true, true, false];
+    ///
+    /// let tail = bv.split_off(3);
+    ///
+    /// assert_eq!(bv, boolvec![true, true, false]);
+    /// assert_eq!(tail, boolvec![true, false, false, false, true, true, false]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.length;
+        if at > len {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+
+        let tail = self.copy_bits(at, len);
+        self.truncate(at);
+
+        tail
+    }
+
+    /// Returns the values that are `true` in either BoolVec as a new BoolVec,
+    /// check BoolVec::union_with() docs for more infos about `policy`
+    /// ```rust
+    /// use bool_vec::{boolvec, LengthPolicy};
+    ///
+    /// let bv1 = boolvec![true, true, false];
+    /// let bv2 = boolvec![false, true, true, true];
+    ///
+    /// assert_eq!(bv1.union(&bv2, LengthPolicy::ZeroExtend), Ok(boolvec![true, true, true, true]));
+    /// assert!(bv1.union(&bv2, LengthPolicy::Exact).is_err());
+    /// ```
+    pub fn union<B: Allocator>(
+        &self,
+        other: &BoolVec<B>,
+        policy: LengthPolicy,
+    ) -> Result<Self, BoolVecError> {
+        let mut result = self.clone();
+        result.union_with(other, policy)?;
+
+        Ok(result)
+    }
+
+    /// Returns the values that are `true` in both BoolVecs as a new BoolVec,
+    /// check BoolVec::intersect_with() docs for more infos about `policy`
+    /// ```rust
+    /// use bool_vec::{boolvec, LengthPolicy};
+    ///
+    /// let bv1 = boolvec![true, true, false];
+    /// let bv2 = boolvec![false, true, true, true];
+    ///
+    /// assert_eq!(bv1.intersection(&bv2, LengthPolicy::ZeroExtend), Ok(boolvec![false, true, false, false]));
+    /// assert!(bv1.intersection(&bv2, LengthPolicy::Exact).is_err());
+    /// ```
+    pub fn intersection<B: Allocator>(
+        &self,
+        other: &BoolVec<B>,
+        policy: LengthPolicy,
+    ) -> Result<Self, BoolVecError> {
+        let mut result = self.clone();
+        result.intersect_with(other, policy)?;
+
+        Ok(result)
+    }
+
+    /// Returns the values that are `true` in `self` but not in `other` as a new BoolVec,
+    /// check BoolVec::difference_with() docs for more infos about `policy`
+    /// ```rust
+    /// use bool_vec::{boolvec, LengthPolicy};
+    ///
+    /// let bv1 = boolvec![true, true, false];
+    /// let bv2 = boolvec![false, true, true, true];
+    ///
+    /// assert_eq!(bv1.difference(&bv2, LengthPolicy::ZeroExtend), Ok(boolvec![true, false, false, false]));
+    /// assert!(bv1.difference(&bv2, LengthPolicy::Exact).is_err());
+    /// ```
+    pub fn difference<B: Allocator>(
+        &self,
+        other: &BoolVec<B>,
+        policy: LengthPolicy,
+    ) -> Result<Self, BoolVecError> {
+        let mut result = self.clone();
+        result.difference_with(other, policy)?;
+
+        Ok(result)
+    }
+
+    /// Returns the values that are `true` in exactly one of the two BoolVecs as a new BoolVec,
+    /// check BoolVec::symmetric_difference_with() docs for more infos about `policy`
+    /// ```rust
+    /// use bool_vec::{boolvec, LengthPolicy};
+    ///
+    /// let bv1 = boolvec![true, true, false];
+    /// let bv2 = boolvec![false, true, true, true];
+    ///
+    /// assert_eq!(bv1.symmetric_difference(&bv2, LengthPolicy::ZeroExtend), Ok(boolvec![true, false, true, true]));
+    /// assert!(bv1.symmetric_difference(&bv2, LengthPolicy::Exact).is_err());
+    /// ```
+    pub fn symmetric_difference<B: Allocator>(
+        &self,
+        other: &BoolVec<B>,
+        policy: LengthPolicy,
+    ) -> Result<Self, BoolVecError> {
+        let mut result = self.clone();
+        result.symmetric_difference_with(other, policy)?;
+
+        Ok(result)
+    }
+
+    /// Copies the values from `start` (included) to `end` (excluded) into a new BoolVec,
+    /// a whole byte at a time
+    fn copy_bits(&self, start: usize, end: usize) -> Self {
+        let len = end - start;
+        let mut copy = Self::with_capacity_in(len, self.allocator().clone());
+        copy.order = self.order;
+
+        let byte_len = bytes_for(len);
+
+        for (byte_index, byte) in copy.raw_bytes_mut()[..byte_len].iter_mut().enumerate() {
+            *byte = self.order.convert(self.read_byte(start + byte_index * 8));
+        }
+
+        // The last byte might contain values coming after the copied range
+        copy.length = len;
+        copy.clear_unused_bits();
+
+        copy
+    }
+
+    /// Combines the words of `self` and `other` with `op`, returning the results as a new BoolVec
+    /// as long as the shortest of the two
+    fn combined<B, F>(&self, other: &BoolVec<B>, op: F) -> Self
+    where
+        B: Allocator,
+        F: Fn(u64, u64) -> u64 + Sync,
+    {
+        let len = self.length.min(other.length);
+        let word_len = words_for(len);
+
+        let mut result = Self::with_capacity_in(len, self.allocator().clone());
+        result.order = self.order;
+
+        // Words of `other` are converted to the bit order of `self` first
+        let (order, other_order) = (self.order, other.order);
+        let op = |a, b| op(a, order.convert_word_from(other_order, b));
+
+        result.words[..word_len].copy_from_slice(&self.words[..word_len]);
+        apply_words(&mut result.words[..word_len], &other.words[..word_len], op);
+
+        // Values past the end might have been set by the combination
+        result.length = len;
+        result.clear_unused_bits();
+
+        result
+    }
+}
+
+impl<A: Allocator, B: Allocator> PartialEq<BoolVec<B>> for BoolVec<A> {
+    /// Compares the values of two BoolVecs a whole word at a time
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv1 = boolvec![true; 200];
+    /// let bv2 = boolvec![true; 199];
+    ///
+    /// assert_ne!(bv1, bv2);
+    ///
+    /// bv1.pop();
+    ///
+    /// assert_eq!(bv1, bv2);
+    /// ```
+    fn eq(&self, other: &BoolVec<B>) -> bool {
+        if self.length != other.length {
+            return false;
+        }
+
+        // Whole words are compared directly, while the last word is masked,
+        // so that bits past the end don't need to be zero for equality to work
+        let full_words = self.length / 64;
+        let remainder = self.length % 64;
+
+        if self.order != other.order {
+            // Words of `other` are compared once converted to the bit order of `self`
+            let convert = |word| self.order.convert_word_from(other.order, word);
+
+            let last_equal = remainder == 0
+                || (self.words[full_words] ^ convert(other.words[full_words]))
+                    & self.word_mask(remainder)
+                    == 0;
+
+            return last_equal
+                && self.words[..full_words]
+                    .iter()
+                    .zip(&other.words[..full_words])
+                    .all(|(&a, &b)| a == convert(b));
+        }
+
+        if remainder != 0 {
+            let diff = self.words[full_words] ^ other.words[full_words];
+
+            if diff & self.word_mask(remainder) != 0 {
+                return false;
+            }
+        }
+
+        #[cfg(feature = "simd")]
+        if let Some(equal) = simd::eq_words(&self.words[..full_words], &other.words[..full_words]) {
+            return equal;
+        }
+
+        self.words[..full_words] == other.words[..full_words]
+    }
+}
+
+impl<A: Allocator> Eq for BoolVec<A> {}
+
+impl<A: Allocator> Hash for BoolVec<A> {
+    /// Hashes the length of the BoolVec, then its values a whole word at a time.
+    /// Just like with PartialEq, bits past the end are masked out.
+    ///
+    /// The hash is the same as the one of a BoolSlice holding the same values:
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use std::hash::BuildHasher;
+    /// use bool_vec::boolvec;
+    ///
+    /// let patterns = [boolvec![true, false], boolvec![true; 100], boolvec![true, false]];
+    ///
+    /// let unique: HashSet<_> = patterns.into_iter().collect();
+    ///
+    /// assert_eq!(unique.len(), 2);
+    ///
+    /// let bv = boolvec![false, true, false];
+    /// let hasher = unique.hasher();
+    ///
+    /// assert_eq!(hasher.hash_one(bv.slice(1..)), hasher.hash_one(boolvec![true, false]));
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.length);
+
+        // Words are hashed as big-endian integers stored most significant bit first,
+        // just like BoolSlice::chunks_u64() yields them
+        let full_words = self.length / 64;
+        for &word in &self.words[..full_words] {
+            state.write_u64(self.msb0_word(word));
+        }
+
+        let remainder = self.length % 64;
+        if remainder != 0 {
+            let word = self.msb0_word(self.words[full_words]) & !(u64::MAX >> remainder);
+
+            state.write_u64(word);
+        }
+    }
+}
+
+impl<A: Allocator, B: Allocator> PartialOrd<BoolVec<B>> for BoolVec<A> {
+    fn partial_cmp(&self, other: &BoolVec<B>) -> Option<Ordering> {
+        Some(self.cmp_values(other))
+    }
+}
+
+impl<A: Allocator> Ord for BoolVec<A> {
+    /// Compares two BoolVecs lexicographically, just like `Vec<bool>` does: `false` comes before `true`,
+    /// and a BoolVec comes before the longer ones it is a prefix of.
+    ///
+    /// Values are compared a whole word at a time, stopping at the first word that differs.
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    /// use bool_vec::boolvec;
+    ///
+    /// assert!(boolvec![false, true, true] < boolvec![true, false]);
+    /// assert!(boolvec![true, false] < boolvec![true, false, false]);
     ///
-    /// assert!(bv.is_empty());
+    /// let mut patterns = vec![boolvec![true; 100], boolvec![false, true], boolvec![]];
+    /// patterns.sort();
+    ///
+    /// assert_eq!(patterns, vec![boolvec![], boolvec![false, true], boolvec![true; 100]]);
+    ///
+    /// let set: BTreeSet<_> = patterns.into_iter().collect();
+    ///
+    /// assert_eq!(set.first(), Some(&boolvec![]));
     /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_values(other)
+    }
+}
+
+impl<A: Allocator> PartialEq<BoolSlice<'_>> for BoolVec<A> {
+    fn eq(&self, other: &BoolSlice<'_>) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<A: Allocator> PartialEq<BoolSliceMut<'_>> for BoolVec<A> {
+    fn eq(&self, other: &BoolSliceMut<'_>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<A: Allocator> Extend<bool> for BoolVec<A> {
+    /// Appends every bool yielded by the iterator to the back of the BoolVec
+    /// ```rust
+    /// use bool_vec::boolvec;
     ///
-    /// ---
+    /// let mut bv = boolvec![true];
+    ///
+    /// bv.extend([false, true]);
+    /// bv.extend((0..20).map(|x| x % 2 == 0));
     ///
+    /// assert_eq!(bv.len(), 23);
+    /// assert_eq!(bv.get(2), Some(true));
+    /// assert_eq!(bv.get(4), Some(false));
+    /// ```
+    fn extend<T: IntoIterator<Item = bool>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+
+        // Allocate the minimum expected amount of values in one go
+        self.grow(self.length + iter.size_hint().0);
+
+        for b in iter {
+            self.push(b);
+        }
+    }
+}
+
+impl<'a, A: Allocator> Extend<&'a bool> for BoolVec<A> {
+    /// Appends every bool yielded by the iterator to the back of the BoolVec
     /// ```rust
     /// use bool_vec::boolvec;
     ///
     /// let mut bv = boolvec![true];
-    /// bv.pop();
     ///
-    /// assert!(bv.is_empty());
+    /// bv.extend(&[false, true]);
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true]);
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.length == 0
+    fn extend<T: IntoIterator<Item = &'a bool>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().copied());
     }
+}
 
-    /// Returns the capacity of the underlying vector that stores the values.
-    /// This function is here just for testing purposes
+impl Default for BoolVec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromIterator<bool> for BoolVec {
+    /// Collects every bool yielded by the iterator into a new BoolVec
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
     ///
-    /// This capacity is expressed in bytes, while the normal capacity of BoolVec is expressed in bits.
+    /// let bv: BoolVec = [1, 0, 3].iter().map(|x| *x > 0).collect();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true]);
+    /// ```
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        let iter = iter.into_iter();
+
+        let mut bool_vec = Self::with_capacity(iter.size_hint().0);
+        bool_vec.extend(iter);
+
+        bool_vec
+    }
+}
+
+impl<'a> FromIterator<&'a bool> for BoolVec {
+    /// Collects every bool yielded by the iterator into a new BoolVec
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let bv: BoolVec = [true, false, true].iter().collect();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true]);
+    /// ```
+    fn from_iter<T: IntoIterator<Item = &'a bool>>(iter: T) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl FromStr for BoolVec {
+    type Err = BoolVecError;
+
+    /// Parses a string of ones and zeros, like the ones printed by Display, into a new BoolVec.
+    ///
+    /// The string may start with a `0b` prefix, and values may be separated with underscores or spaces.
+    /// Any other character returns a BoolVecError::InvalidCharacter
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec, BoolVecError};
+    ///
+    /// let bv: BoolVec = "0b1011_0010 1".parse().unwrap();
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, true, false, false, true, false, true]);
+    /// assert_eq!(bv.to_string().parse::<BoolVec>(), Ok(bv));
+    ///
+    /// assert_eq!(
+    ///     "10x1".parse::<BoolVec>(),
+    ///     Err(BoolVecError::InvalidCharacter { character: 'x', index: 2 })
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, BoolVecError> {
+        let (offset, digits) = match s.strip_prefix("0b") {
+            Some(digits) => (2, digits),
+            None => (0, s),
+        };
+
+        let mut bool_vec = Self::with_capacity(digits.len());
+
+        for (index, character) in digits.char_indices() {
+            match character {
+                '0' => bool_vec.push(false),
+                '1' => bool_vec.push(true),
+                '_' | ' ' => {}
+                _ => {
+                    return Err(BoolVecError::InvalidCharacter {
+                        character,
+                        index: index + offset,
+                    })
+                }
+            }
+        }
+
+        Ok(bool_vec)
+    }
+}
+
+/// Implements a bitwise operator for BoolVec, applying it a whole word at a time
+macro_rules! impl_bitwise_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt, $doc:literal) => {
+        impl<A: Allocator + Clone, B: Allocator> $trait<&BoolVec<B>> for &BoolVec<A> {
+            type Output = BoolVec<A>;
+
+            #[doc = $doc]
+            /// The result is as long as the shortest of the two BoolVecs.
+            ///
+            /// With the `rayon` feature, huge BoolVecs are processed in parallel.
+            fn $method(self, rhs: &BoolVec<B>) -> Self::Output {
+                self.combined(rhs, |a, b| a $op b)
+            }
+        }
+
+        impl<A: Allocator, B: Allocator> $trait<&BoolVec<B>> for BoolVec<A> {
+            type Output = BoolVec<A>;
+
+            #[doc = $doc]
+            /// The result is as long as the shortest of the two BoolVecs.
+            ///
+            /// With the `rayon` feature, huge BoolVecs are processed in parallel.
+            fn $method(mut self, rhs: &BoolVec<B>) -> Self::Output {
+                self.truncate(rhs.length);
+                self.combine_in_place(rhs, |a, b| a $op b);
+
+                self
+            }
+        }
+
+        impl<A: Allocator, B: Allocator> $assign_trait<&BoolVec<B>> for BoolVec<A> {
+            #[doc = $doc]
+            /// If `rhs` is shorter, the values past its end are left untouched.
+            ///
+            /// With the `rayon` feature, huge BoolVecs are processed in parallel.
+            fn $assign_method(&mut self, rhs: &BoolVec<B>) {
+                self.combine_in_place(rhs, |a, b| a $op b);
+            }
+        }
+    };
+}
+
+impl_bitwise_op!(
+    BitAnd,
+    bitand,
+    BitAndAssign,
+    bitand_assign,
+    &,
+    "Computes the logical AND of two BoolVecs, value by value.
+```rust
+use bool_vec::boolvec;
+
+let bv1 = boolvec![true, true, false, false];
+let bv2 = boolvec![true, false, true, false];
+
+assert_eq!(&bv1 & &bv2, boolvec![true, false, false, false]);
+```"
+);
+
+impl_bitwise_op!(
+    BitOr,
+    bitor,
+    BitOrAssign,
+    bitor_assign,
+    |,
+    "Computes the logical OR of two BoolVecs, value by value.
+```rust
+use bool_vec::boolvec;
+
+let mut bv1 = boolvec![true, true, false, false];
+let bv2 = boolvec![true, false, true, false];
+
+bv1 |= &bv2;
+
+assert_eq!(bv1, boolvec![true, true, true, false]);
+```"
+);
+
+impl_bitwise_op!(
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    ^,
+    "Computes the logical XOR of two BoolVecs, value by value.
+```rust
+use bool_vec::boolvec;
+
+let bv1 = boolvec![true, true, false, false];
+let bv2 = boolvec![true, false, true];
+
+assert_eq!(bv1 ^ &bv2, boolvec![false, true, true]);
+```"
+);
+
+impl<A: Allocator + Clone> Not for &BoolVec<A> {
+    type Output = BoolVec<A>;
+
+    /// Negates every value of the BoolVec
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// assert_eq!(!&bv, boolvec![false, true, false]);
+    /// ```
+    fn not(self) -> Self::Output {
+        !self.clone()
+    }
+}
+
+impl<A: Allocator> Not for BoolVec<A> {
+    type Output = BoolVec<A>;
+
+    /// Negates every value of the BoolVec
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// assert_eq!(!bv, boolvec![false, true, false]);
+    /// ```
+    fn not(mut self) -> Self::Output {
+        let word_len = words_for(self.length);
+
+        for word in &mut self.words[..word_len] {
+            *word = !*word;
+        }
+
+        // Values past the end must stay zero
+        self.clear_unused_bits();
+
+        self
+    }
+}
+
+macro_rules! impl_shift_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $shift:ident, $doc:literal) => {
+        impl<A: Allocator + Clone> $trait<usize> for &BoolVec<A> {
+            type Output = BoolVec<A>;
+
+            #[doc = $doc]
+            fn $method(self, rhs: usize) -> Self::Output {
+                self.clone().$method(rhs)
+            }
+        }
+
+        impl<A: Allocator> $trait<usize> for BoolVec<A> {
+            type Output = BoolVec<A>;
+
+            #[doc = $doc]
+            fn $method(mut self, rhs: usize) -> Self::Output {
+                self.$shift(rhs, false);
+
+                self
+            }
+        }
+
+        impl<A: Allocator> $assign_trait<usize> for BoolVec<A> {
+            #[doc = $doc]
+            fn $assign_method(&mut self, rhs: usize) {
+                self.$shift(rhs, false);
+            }
+        }
+    };
+}
+
+impl_shift_op!(
+    Shl,
+    shl,
+    ShlAssign,
+    shl_assign,
+    shift_left,
+    "Moves every value `rhs` positions towards the start of the BoolVec, filling the last ones with false.
+Check BoolSliceMut::shift_left() to fill them with another value.
+```rust
+use bool_vec::boolvec;
+
+let mut bv = boolvec![true, false, true, true];
+
+assert_eq!(&bv << 2, boolvec![true, true, false, false]);
+
+bv <<= 1;
+
+assert_eq!(bv, boolvec![false, true, true, false]);
+```"
+);
+
+impl_shift_op!(
+    Shr,
+    shr,
+    ShrAssign,
+    shr_assign,
+    shift_right,
+    "Moves every value `rhs` positions towards the end of the BoolVec, filling the first ones with false.
+Check BoolSliceMut::shift_right() to fill them with another value.
+```rust
+use bool_vec::boolvec;
+
+let mut bv = boolvec![true, false, true, true];
+
+assert_eq!(&bv >> 2, boolvec![false, false, true, false]);
+
+bv >>= 1;
+
+assert_eq!(bv, boolvec![false, true, false, true]);
+```"
+);
+
+impl<A: Allocator> Debug for BoolVec<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.as_slice(), f)
+    }
+}
+
+impl<A: Allocator> Display for BoolVec<A> {
+    /// Prints the values as a string of ones and zeros, check BoolSlice's Display docs for more infos
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv.to_string(), "101");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.as_slice(), f)
+    }
+}
+
+/// Implements a radix formatting trait for BoolVec, printing its bytes with the same trait
+macro_rules! impl_radix_fmt {
+    ($($trait:ident),*) => {
+        $(
+            impl<A: Allocator> $trait for BoolVec<A> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    if self.length == 0 {
+                        return f.write_str("[]");
+                    }
+
+                    let byte_len = bytes_for(self.length);
+
+                    fmt_bytes(self.raw_bytes()[..byte_len].iter().copied(), f, $trait::fmt)
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_fmt!(Binary, Octal, LowerHex, UpperHex);
+
+/// Prints a list of bytes with `fmt_byte`, applying the formatting specifiers to each byte.
+/// Prints a byte per line if pretty printing
+fn fmt_bytes<I: ExactSizeIterator<Item = u8>>(
+    bytes: I,
+    f: &mut std::fmt::Formatter<'_>,
+    fmt_byte: fn(&u8, &mut std::fmt::Formatter<'_>) -> std::fmt::Result,
+) -> std::fmt::Result {
+    let byte_len = bytes.len();
+    let mut counter = 0;
+
+    f.write_char('[')?;
+
+    if f.alternate() {
+        f.write_char('\n')?;
+    }
+
+    for byte in bytes {
+        if f.alternate() {
+            f.write_str("    ")?;
+        }
+
+        fmt_byte(&byte, f)?;
+
+        counter += 1;
+        if counter < byte_len {
+            f.write_str(", ")?;
+            if f.alternate() {
+                f.write_char('\n')?;
+            }
+        }
+    }
+
+    if f.alternate() {
+        f.write_char('\n')?;
+    }
+
+    f.write_char(']')
+}
+
+impl<'a, A: Allocator> IntoIterator for &'a BoolVec<A> {
+    type Item = bool;
+
+    type IntoIter = BoolVecIter<'a>;
+
+    /// Convert BoolVec into an iterator
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<A: Allocator> IntoIterator for BoolVec<A> {
+    type Item = bool;
+
+    type IntoIter = BoolVecIntoIter<A>;
+
+    /// Convert BoolVec into a consuming iterator
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// let mut count = 0;
+    /// for b in bv {
+    ///     count += b as usize;
+    /// }
+    ///
+    /// assert_eq!(count, 2);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let end = self.length;
+
+        BoolVecIntoIter {
+            vec: self,
+            counter: 0,
+            end,
+        }
+    }
+}
+
+impl<'a> Iterator for BoolVecIter<'a> {
+    type Item = bool;
+
+    /// Convert return next
+    /// Advances the iterator and returns the next value.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
+        }
+
+        let item = self.slice.get(self.counter)?;
+        self.counter += 1;
+
+        Some(item)
+    }
+
+    /// Returns the exact number of values left in the iterator
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true; 10];
+    ///
+    /// let mut iter = bv.into_iter();
+    /// iter.next();
+    ///
+    /// assert_eq!(iter.size_hint(), (9, Some(9)));
+    /// assert_eq!(iter.len(), 9);
+    /// ```
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.counter;
+
+        (remaining, Some(remaining))
+    }
+
+    /// Returns the number of values left in the iterator, without reading any of them
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true; 10];
+    ///
+    /// assert_eq!(bv.into_iter().count(), 10);
+    /// ```
+    fn count(self) -> usize {
+        self.end - self.counter
+    }
+
+    /// Skips `n` values without reading them and returns the following one
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false];
+    ///
+    /// let mut iter = bv.into_iter();
+    ///
+    /// assert_eq!(iter.nth(1), Some(false));
+    /// assert_eq!(iter.nth(2), Some(false));
+    /// assert_eq!(iter.nth(0), None);
+    /// ```
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.counter = self.counter.saturating_add(n).min(self.end);
+
+        self.next()
+    }
+
+    /// Returns the last value of the iterator, without reading any of the previous ones
     /// ```rust
     /// use bool_vec::boolvec;
     ///
-    /// let mut bv = boolvec![true; 8];
-    /// bv.push(true);
+    /// let bv = boolvec![true, false, true, true, false];
     ///
-    /// assert_eq!(bv.capacity(), bv.bytes_capacity()*8);
+    /// assert_eq!(bv.into_iter().last(), Some(false));
     /// ```
-    pub fn bytes_capacity(&self) -> usize {
-        self.bytes.capacity()
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
+}
 
-    /// Returns the number of elements of the underlying vector that stores the values.
-    /// This function is here just for testing purposes
-    ///
-    /// This length is expressed in bytes, while the normal length of BoolVec is expressed in bits.
+impl<'a> DoubleEndedIterator for BoolVecIter<'a> {
+    /// Advances the iterator from the back and returns the previous value.
+    /// Returns None when iteration is finished.
     /// ```rust
     /// use bool_vec::boolvec;
     ///
-    /// let mut bv = boolvec![true; 8];
-    ///
-    /// assert_eq!(bv.len(), 8);
-    /// assert_eq!(bv.bytes_len(), 1);
+    /// let bv = boolvec![true, false, false];
     ///
-    /// bv.push(true);
+    /// let reversed: Vec<bool> = bv.into_iter().rev().collect();
     ///
-    /// assert_eq!(bv.len(), 9);
-    /// assert_eq!(bv.bytes_len(), 2);
+    /// assert_eq!(reversed, vec![false, false, true]);
     /// ```
-    pub fn bytes_len(&self) -> usize {
-        self.bytes.len()
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        self.slice.get(self.end)
     }
+}
 
-    /// Copies BoolVec data into a `Vec<bool>`
+impl<'a> ExactSizeIterator for BoolVecIter<'a> {}
+
+impl<'a> FusedIterator for BoolVecIter<'a> {}
+
+impl<A: Allocator> Iterator for BoolVecIntoIter<A> {
+    type Item = bool;
+
+    /// Advances the iterator and returns the next value.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
+        }
+
+        let item = self.vec.get(self.counter)?;
+        self.counter += 1;
+
+        Some(item)
+    }
+
+    /// Returns the exact number of values left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.counter;
+
+        (remaining, Some(remaining))
+    }
+
+    /// Returns the number of values left in the iterator, without reading any of them
+    fn count(self) -> usize {
+        self.end - self.counter
+    }
+
+    /// Skips `n` values without reading them and returns the following one
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.counter = self.counter.saturating_add(n).min(self.end);
+
+        self.next()
+    }
+
+    /// Returns the last value of the iterator, without reading any of the previous ones
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<A: Allocator> DoubleEndedIterator for BoolVecIntoIter<A> {
+    /// Advances the iterator from the back and returns the previous value.
+    /// Returns None when iteration is finished.
     /// ```rust
     /// use bool_vec::boolvec;
-    /// 
-    /// let bv = boolvec![true, false, true];
-    ///
-    /// let vector = bv.into_vec();
     ///
-    /// assert_eq!(vector, vec![true, false, true]);
-    /// ```
+    /// let bv = boolvec![true, false, false];
     ///
-    /// To achieve the opposite (getting a `BoolVec` from a `Vec<bool>` or a `&[bool]`),
-    /// please see BoolVec::from()
+    /// let mut iter = bv.into_iter();
     ///
-    /// *WARNING: This might require a non indifferent amount of memory if you are working
-    /// with a large amount of data, please consider trying to work with BoolVec directly
-    /// if you can*
-    pub fn into_vec(&self) -> Vec<bool> {
-        let mut new_vec = Vec::with_capacity(self.len());
+    /// assert_eq!(iter.next_back(), Some(false));
+    /// assert_eq!(iter.next(), Some(true));
+    /// assert_eq!(iter.next_back(), Some(false));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
+        }
 
-        for b in self {
-            new_vec.push(b);
+        self.end -= 1;
+
+        self.vec.get(self.end)
+    }
+}
+
+impl<A: Allocator> ExactSizeIterator for BoolVecIntoIter<A> {}
+
+impl<A: Allocator> FusedIterator for BoolVecIntoIter<A> {}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = BoolSlice<'a>;
+
+    /// Advances the iterator and returns the next chunk.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
         }
 
-        new_vec
+        let chunk_len = self.chunk_size.min(self.end - self.counter);
+        let chunk = self.slice.slice(self.counter..self.counter + chunk_len);
+        self.counter += chunk_len;
+
+        Some(chunk)
+    }
+
+    /// Returns the exact number of chunks left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.counter).div_ceil(self.chunk_size);
+
+        (remaining, Some(remaining))
     }
 }
 
-impl PartialEq for BoolVec {
-    fn eq(&self, other: &Self) -> bool {
-        if self.length != other.length {
-            return false;
+impl<'a> DoubleEndedIterator for Chunks<'a> {
+    /// Advances the iterator from the back and returns the previous chunk.
+    /// The first chunk returned might be shorter, just like the last chunk returned by next().
+    /// Returns None when iteration is finished.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false];
+    ///
+    /// let mut chunks = bv.chunks(2);
+    ///
+    /// assert_eq!(chunks.next_back().unwrap(), boolvec![false]);
+    /// assert_eq!(chunks.next_back().unwrap(), boolvec![true, true]);
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
         }
 
-        let min_length = if self.bytes_len() <= other.bytes_len() {
-            self.bytes_len()
+        let remainder = (self.end - self.counter) % self.chunk_size;
+        let chunk_len = if remainder == 0 {
+            self.chunk_size
         } else {
-            other.bytes_len()
+            remainder
         };
 
-        self.bytes[..min_length] == other.bytes[..min_length]
+        let chunk = self.slice.slice(self.end - chunk_len..self.end);
+        self.end -= chunk_len;
+
+        Some(chunk)
     }
 }
 
-impl Debug for BoolVec {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.length == 0 {
-            return f.write_str("[]");
-        }
+impl<'a> ExactSizeIterator for Chunks<'a> {}
 
-        if !f.alternate() {
-            return f.debug_list().entries(self).finish();
-        }
+impl<'a> FusedIterator for Chunks<'a> {}
 
-        f.write_str("[\n")?;
+impl<'a> Iterator for Windows<'a> {
+    type Item = BoolSlice<'a>;
 
-        let mut counter = 0;
+    /// Advances the iterator and returns the next window.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
+        }
 
-        for b in self {
-            if counter % 8 == 0 {
-                f.write_str("    ")?;
-            }
+        let window = self
+            .slice
+            .slice(self.counter..self.counter + self.window_size);
+        self.counter += 1;
 
-            f.write_str(b.to_string().as_str())?;
+        Some(window)
+    }
 
-            if b {
-                f.write_char(' ')?;
-            }
+    /// Returns the exact number of windows left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.counter;
 
-            counter += 1;
-            if counter < self.length {
-                f.write_str(", ")?;
-            }
+        (remaining, Some(remaining))
+    }
+}
 
-            if counter % 8 == 0 {
-                f.write_char('\n')?;
-            }
+impl<'a> DoubleEndedIterator for Windows<'a> {
+    /// Advances the iterator from the back and returns the previous window.
+    /// Returns None when iteration is finished.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
         }
 
-        f.write_str("\n]")
+        self.end -= 1;
+
+        Some(self.slice.slice(self.end..self.end + self.window_size))
     }
 }
 
-impl Binary for BoolVec {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.length == 0 {
-            return f.write_str("[]");
+impl<'a> ExactSizeIterator for Windows<'a> {}
+
+impl<'a> FusedIterator for Windows<'a> {}
+
+impl<'a> Iterator for WindowsU64<'a> {
+    type Item = u64;
+
+    /// Advances the iterator and returns the next window.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
         }
 
-        let mut counter = 0;
+        let window = self.slice.read_u64(self.counter, self.window_size);
+        self.counter += 1;
 
-        f.write_char('[')?;
+        Some(window)
+    }
 
-        if f.alternate() {
-            f.write_char('\n')?;
+    /// Returns the exact number of windows left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.counter;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> DoubleEndedIterator for WindowsU64<'a> {
+    /// Advances the iterator from the back and returns the previous window.
+    /// Returns None when iteration is finished.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
         }
 
-        for byte in self.bytes.iter() {
-            if f.alternate() {
-                f.write_str("    ")?;
-            }
+        self.end -= 1;
 
-            std::fmt::Binary::fmt(byte, f)?;
+        Some(self.slice.read_u64(self.end, self.window_size))
+    }
+}
 
-            counter += 1;
-            if counter < self.bytes.len() {
-                f.write_str(", ")?;
-                if f.alternate() {
-                    f.write_char('\n')?;
-                }
+impl<'a> ExactSizeIterator for WindowsU64<'a> {}
+
+impl<'a> FusedIterator for WindowsU64<'a> {}
+
+impl<'a> Iterator for Runs<'a> {
+    type Item = (bool, usize, usize);
+
+    /// Advances the iterator and returns the next run as a `(value, start, len)` tuple.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.counter;
+        let value = self.slice.get(start)?;
+
+        let end = self.slice.find_from(start + 1, !value);
+        self.counter = end;
+
+        Some((value, start, end - start))
+    }
+
+    /// Returns the bounds on the number of runs left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.slice.len() - self.counter;
+
+        (remaining.min(1), Some(remaining))
+    }
+}
+
+impl<'a> FusedIterator for Runs<'a> {}
+
+impl<'a> Iterator for Differences<'a> {
+    type Item = usize;
+
+    /// Advances the iterator and returns the next index where the two BoolSlices differ.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        // Bytes holding the same values are skipped without looking at their bits
+        while self.pending == 0 {
+            if self.counter >= self.end {
+                return None;
             }
+
+            self.pending = self.slice.read_byte(self.counter) ^ self.other.read_byte(self.counter);
+            self.base = self.counter;
+            self.counter += 8;
         }
 
-        if f.alternate() {
-            f.write_char('\n')?;
+        let bit_index = self.pending.leading_zeros() as usize;
+        self.pending &= !(128 >> bit_index);
+
+        Some(self.base + bit_index)
+    }
+
+    /// Returns the bounds on the number of differences left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.count_ones() as usize;
+
+        (
+            pending,
+            Some(pending + self.end.saturating_sub(self.counter)),
+        )
+    }
+}
+
+impl<'a> FusedIterator for Differences<'a> {}
+
+impl<'a> Iterator for Bytes<'a> {
+    type Item = u8;
+
+    /// Advances the iterator and returns the next byte.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
         }
 
-        f.write_char(']')
+        let item = self.slice.read_byte(self.counter * 8);
+        self.counter += 1;
+
+        Some(item)
+    }
+
+    /// Returns the exact number of bytes left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.counter;
+
+        (remaining, Some(remaining))
     }
 }
 
-impl<'a> IntoIterator for &'a BoolVec {
-    type Item = bool;
+impl<'a> DoubleEndedIterator for Bytes<'a> {
+    /// Advances the iterator from the back and returns the previous byte.
+    /// Returns None when iteration is finished.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
+        }
 
-    type IntoIter = BoolVecIter<'a>;
+        self.end -= 1;
 
-    /// Convert BoolVec into an iterator
-    fn into_iter(self) -> Self::IntoIter {
-        BoolVecIter {
-            vec: self,
-            counter: 0,
+        Some(self.slice.read_byte(self.end * 8))
+    }
+}
+
+impl<'a> ExactSizeIterator for Bytes<'a> {}
+
+impl<'a> FusedIterator for Bytes<'a> {}
+
+impl<'a> Iterator for ChunksU64<'a> {
+    type Item = u64;
+
+    /// Advances the iterator and returns the next chunk.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
+        }
+
+        let item = self.slice.read_u64(self.counter * 64, 64);
+        self.counter += 1;
+
+        Some(item)
+    }
+
+    /// Returns the exact number of chunks left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.counter;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> DoubleEndedIterator for ChunksU64<'a> {
+    /// Advances the iterator from the back and returns the previous chunk.
+    /// Returns None when iteration is finished.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.end {
+            return None;
         }
+
+        self.end -= 1;
+
+        Some(self.slice.read_u64(self.end * 64, 64))
     }
 }
 
-impl<'a> Iterator for BoolVecIter<'a> {
+impl<'a> ExactSizeIterator for ChunksU64<'a> {}
+
+impl<'a> FusedIterator for ChunksU64<'a> {}
+
+impl<'a, A: Allocator> Iterator for Drain<'a, A> {
     type Item = bool;
 
-    /// Convert return next
-    /// Advances the iterator and returns the next value.
+    /// Advances the iterator and returns the next drained value.
     /// Returns None when iteration is finished.
     fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.back {
+            return None;
+        }
+
         let item = self.vec.get(self.counter)?;
         self.counter += 1;
 
         Some(item)
     }
+
+    /// Returns the exact number of values left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.counter;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, A: Allocator> DoubleEndedIterator for Drain<'a, A> {
+    /// Advances the iterator from the back and returns the previous drained value.
+    /// Returns None when iteration is finished.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, false, true];
+    ///
+    /// let drained: Vec<bool> = bv.drain(..3).rev().collect();
+    ///
+    /// assert_eq!(drained, vec![false, false, true]);
+    /// assert_eq!(bv, boolvec![true]);
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        self.vec.get(self.back)
+    }
+}
+
+impl<'a, A: Allocator> ExactSizeIterator for Drain<'a, A> {}
+
+impl<'a, A: Allocator> FusedIterator for Drain<'a, A> {}
+
+impl<'a, A: Allocator> Drop for Drain<'a, A> {
+    /// Removes the drained range from the BoolVec
+    fn drop(&mut self) {
+        self.vec.remove_bits(self.start, self.end);
+    }
+}
+
+impl<'a, I: Iterator<Item = bool>, A: Allocator> Iterator for Splice<'a, I, A> {
+    type Item = bool;
+
+    /// Advances the iterator and returns the next removed value.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain.next()
+    }
+
+    /// Returns the exact number of values left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<'a, I: Iterator<Item = bool>, A: Allocator> DoubleEndedIterator for Splice<'a, I, A> {
+    /// Advances the iterator from the back and returns the previous removed value.
+    /// Returns None when iteration is finished.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<'a, I: Iterator<Item = bool>, A: Allocator> ExactSizeIterator for Splice<'a, I, A> {}
+
+impl<'a, I: Iterator<Item = bool>, A: Allocator> FusedIterator for Splice<'a, I, A> {}
+
+impl<'a, I: Iterator<Item = bool>, A: Allocator> Drop for Splice<'a, I, A> {
+    /// Replaces the drained range with the values yielded by the replacement iterator
+    fn drop(&mut self) {
+        let vec = &mut *self.drain.vec;
+
+        let mut replacement: BoolVec = self.replace_with.by_ref().collect();
+        let mut tail = vec.slice(self.drain.end..).to_owned();
+
+        vec.truncate(self.drain.start);
+        vec.append(&mut replacement);
+        vec.append(&mut tail);
+
+        // The range has already been replaced, so Drain must not remove anything when dropped
+        self.drain.end = self.drain.start;
+    }
 }
 
 #[macro_export]
@@ -0,0 +1,239 @@
+//! Parallel iterators over a BoolSlice, only available with the `rayon` feature.
+//!
+//! Whenever iterators are not required to split at an exact index,
+//! work is split on byte boundaries, so that no byte is ever shared between two threads.
+
+use rayon::iter::plumbing::{
+    bridge, bridge_unindexed, Consumer, Folder, Producer, ProducerCallback, UnindexedConsumer,
+    UnindexedProducer,
+};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+use crate::{BoolSlice, BoolVecIter};
+
+/// Parallel iterator over the values of a BoolSlice, check BoolSlice::par_iter() docs for more infos
+pub struct ParIter<'a> {
+    slice: BoolSlice<'a>,
+}
+
+/// Parallel iterator over the indexes of the true values of a BoolSlice,
+/// check BoolSlice::par_iter_ones() docs for more infos
+pub struct ParIterOnes<'a> {
+    slice: BoolSlice<'a>,
+}
+
+/// Values of a BoolSlice from `start` (included) to `end` (excluded), shared between threads
+struct BitsProducer<'a> {
+    slice: BoolSlice<'a>,
+    start: usize,
+    end: usize,
+}
+
+/// Indexes of the true values of a BoolSlice from `start` (included) to `end` (excluded),
+/// shared between threads
+struct OnesProducer<'a> {
+    slice: BoolSlice<'a>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> BoolSlice<'a> {
+    /// Returns a parallel iterator over the values of the BoolSlice.
+    /// The iterator is indexed, so it can be used with methods like `enumerate()` and `zip()`.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    /// use rayon::prelude::*;
+    ///
+    /// let bv = boolvec![true, false, true, true];
+    ///
+    /// let trues = bv.par_iter().filter(|b| *b).count();
+    ///
+    /// assert_eq!(trues, 3);
+    ///
+    /// let values: Vec<bool> = bv.par_iter().collect();
+    ///
+    /// assert_eq!(values, vec![true, false, true, true]);
+    /// ```
+    pub fn par_iter(&self) -> ParIter<'a> {
+        ParIter { slice: *self }
+    }
+
+    /// Returns a parallel iterator over the indexes of the true values of the BoolSlice.
+    /// Bytes not containing any true value are skipped at once.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut bv = boolvec![false; 100];
+    /// bv.set(3, true);
+    /// bv.set(70, true);
+    ///
+    /// let ones: Vec<usize> = bv.par_iter_ones().collect();
+    ///
+    /// assert_eq!(ones, vec![3, 70]);
+    ///
+    /// // Each thread only searches its own range, so sparse BoolVecs are scanned just once
+    /// let mut sparse = boolvec![false; 50_000_000];
+    /// sparse.set(10, true);
+    ///
+    /// assert_eq!(sparse.par_iter_ones().collect::<Vec<usize>>(), vec![10]);
+    /// ```
+    pub fn par_iter_ones(&self) -> ParIterOnes<'a> {
+        ParIterOnes { slice: *self }
+    }
+}
+
+/// Returns an index splitting the range from `start` to `end` roughly in half
+/// on a byte boundary, or None if the range is too small to be split
+fn split_index(start: usize, end: usize) -> Option<usize> {
+    let mid = (start + (end - start) / 2) / 8 * 8;
+
+    if mid <= start {
+        return None;
+    }
+
+    Some(mid)
+}
+
+impl<'a> ParallelIterator for ParIter<'a> {
+    type Item = bool;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.slice.len())
+    }
+}
+
+impl<'a> IndexedParallelIterator for ParIter<'a> {
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(BitsProducer {
+            slice: self.slice,
+            start: 0,
+            end: self.slice.len(),
+        })
+    }
+}
+
+impl<'a> ParallelIterator for ParIterOnes<'a> {
+    type Item = usize;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        let producer = OnesProducer {
+            slice: self.slice,
+            start: 0,
+            end: self.slice.len(),
+        };
+
+        bridge_unindexed(producer, consumer)
+    }
+}
+
+impl<'a> Producer for BitsProducer<'a> {
+    type Item = bool;
+
+    type IntoIter = BoolVecIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.slice(self.start..self.end).iter()
+    }
+
+    /// Indexed iterators require splitting at the exact index, even if it's not on a byte boundary.
+    /// This is fine since values are only read
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.start + index;
+
+        (
+            BitsProducer {
+                slice: self.slice,
+                start: self.start,
+                end: mid,
+            },
+            BitsProducer {
+                slice: self.slice,
+                start: mid,
+                end: self.end,
+            },
+        )
+    }
+}
+
+impl<'a> UnindexedProducer for OnesProducer<'a> {
+    type Item = usize;
+
+    fn split(self) -> (Self, Option<Self>) {
+        let Some(mid) = split_index(self.start, self.end) else {
+            return (self, None);
+        };
+
+        let right = OnesProducer {
+            slice: self.slice,
+            start: mid,
+            end: self.end,
+        };
+
+        let left = OnesProducer {
+            slice: self.slice,
+            start: self.start,
+            end: mid,
+        };
+
+        (left, Some(right))
+    }
+
+    fn fold_with<F: Folder<Self::Item>>(self, mut folder: F) -> F {
+        // Only the range of this producer is searched, so that a range without true values
+        // doesn't get scanned up to the end of the whole slice
+        let slice = self.slice.slice(self.start..self.end);
+        let mut int_index = slice.find_from(0, true);
+
+        while int_index < slice.len() && !folder.full() {
+            folder = folder.consume(self.start + int_index);
+            int_index = slice.find_from(int_index + 1, true);
+        }
+
+        folder
+    }
+}
+
+/// Minimum number of bytes a bulk operation must process for it to be split between threads
+pub(crate) const PARALLEL_THRESHOLD: usize = 1 << 20;
+
+/// Number of bytes processed by a single thread at a time during bulk operations
+const CHUNK_SIZE: usize = 1 << 16;
+
+/// Parallel version of crate::apply_words()
+pub(crate) fn apply_words<F: Fn(u64, u64) -> u64 + Sync>(lhs: &mut [u64], rhs: &[u64], op: F) {
+    lhs.par_chunks_mut(CHUNK_SIZE / 8)
+        .zip(rhs.par_chunks(CHUNK_SIZE / 8))
+        .for_each(|(lhs, rhs)| crate::apply_words_on_thread(lhs, rhs, &op));
+}
+
+/// Parallel version of crate::count_ones_bytes()
+pub(crate) fn count_ones_bytes(bytes: &[u8]) -> usize {
+    bytes
+        .par_chunks(CHUNK_SIZE)
+        .map(|chunk| {
+            // Chunks are smaller than the threshold, so they're always counted by a single thread
+            crate::count_ones_bytes(chunk)
+        })
+        .sum()
+}
+
+/// Parallel version of crate::fill_bytes()
+pub(crate) fn fill_bytes(bytes: &mut [u8], value: u8) {
+    bytes
+        .par_chunks_mut(CHUNK_SIZE)
+        .for_each(|chunk| chunk.fill(value));
+}
@@ -0,0 +1,278 @@
+//! Index answering rank and select queries over a BoolVec which isn't modified anymore.
+
+use std::ops::{Deref, Range};
+
+use crate::BoolVec;
+
+/// Number of values counted by each entry of RankSelect::blocks
+const BLOCK_LEN: usize = 512;
+
+/// Number of values counted by each entry of RankSelect::superblocks
+const SUPERBLOCK_LEN: usize = 1 << 16;
+
+/// Number of words in each block
+const BLOCK_WORDS: usize = BLOCK_LEN / 64;
+
+/// Number of blocks in each superblock
+const SUPERBLOCK_BLOCKS: usize = SUPERBLOCK_LEN / BLOCK_LEN;
+
+/// A BoolVec along with counts of its `true` values, answering rank queries in constant time
+/// and select queries in logarithmic time.
+///
+/// The number of `true` values before every 65536 values is stored as a usize,
+/// and the number of `true` values before every 512 values since the start of those 65536 as a u16,
+/// which takes around 3.5% more memory than the BoolVec itself.
+/// Rank queries then only need to count the `true` values of up to 8 words.
+///
+/// Since the counts must stay correct, the BoolVec can't be modified while it's indexed.
+/// It can be read through Deref, and moved back out with RankSelect::into_bool_vec().
+/// ```rust
+/// use bool_vec::{boolvec, RankSelect};
+///
+/// let index = RankSelect::new(boolvec![true, false, true, true, false]);
+///
+/// assert_eq!(index.rank1(3), 2);
+/// assert_eq!(index.rank0(3), 1);
+/// assert_eq!(index.select1(2), Some(3));
+/// assert_eq!(index.select0(1), Some(4));
+/// assert_eq!(index.select0(2), None);
+///
+/// // The indexed values can still be read
+/// assert_eq!(index.get(1), Some(false));
+/// ```
+pub struct RankSelect {
+    bits: BoolVec,
+
+    /// Number of `true` values before each superblock
+    superblocks: Vec<usize>,
+
+    /// Number of `true` values before each block, since the start of its superblock
+    blocks: Vec<u16>,
+
+    /// Total number of `true` values
+    ones: usize,
+}
+
+impl RankSelect {
+    /// Indexes `bits`, counting its `true` values a whole word at a time
+    pub fn new(bits: BoolVec) -> Self {
+        let words = bits.as_raw_slice();
+
+        let mut superblocks = Vec::with_capacity(words.len().div_ceil(SUPERBLOCK_LEN / 64));
+        let mut blocks = Vec::with_capacity(words.len().div_ceil(BLOCK_WORDS));
+        let mut ones = 0;
+
+        for (block_index, block) in words.chunks(BLOCK_WORDS).enumerate() {
+            if block_index % SUPERBLOCK_BLOCKS == 0 {
+                superblocks.push(ones);
+            }
+
+            blocks.push((ones - superblocks[superblocks.len() - 1]) as u16);
+            ones += block
+                .iter()
+                .map(|word| word.count_ones() as usize)
+                .sum::<usize>();
+        }
+
+        Self {
+            bits,
+            superblocks,
+            blocks,
+            ones,
+        }
+    }
+
+    /// Returns the number of `true` values in the indexed BoolVec, without counting them
+    pub fn count_ones(&self) -> usize {
+        self.ones
+    }
+
+    /// Returns the number of `true` values before `index`, in constant time.
+    ///
+    /// Panics if `index` is greater than the length of the indexed BoolVec.
+    /// ```rust
+    /// use bool_vec::{boolvec, RankSelect};
+    ///
+    /// let index = RankSelect::new(boolvec![true; 1000]);
+    ///
+    /// assert_eq!(index.rank1(0), 0);
+    /// assert_eq!(index.rank1(700), 700);
+    /// assert_eq!(index.rank1(1000), 1000);
+    /// ```
+    pub fn rank1(&self, index: usize) -> usize {
+        let len = self.bits.len();
+        assert!(
+            index <= len,
+            "index (is {index}) should be <= len (is {len})"
+        );
+
+        if index == len {
+            return self.ones;
+        }
+
+        let block = index / BLOCK_LEN;
+        let words = self.bits.as_raw_slice();
+        let word_index = index / 64;
+
+        let mut ones = self.superblocks[index / SUPERBLOCK_LEN] + self.blocks[block] as usize;
+
+        for word in &words[block * BLOCK_WORDS..word_index] {
+            ones += word.count_ones() as usize;
+        }
+
+        // Only the values of the last word coming before `index` are counted
+        let remainder = index % 64;
+        if remainder != 0 {
+            ones +=
+                (self.bits.msb0_word(words[word_index]) >> (64 - remainder)).count_ones() as usize;
+        }
+
+        ones
+    }
+
+    /// Returns the number of `false` values before `index`, in constant time.
+    ///
+    /// Panics if `index` is greater than the length of the indexed BoolVec.
+    /// ```rust
+    /// use bool_vec::{boolvec, RankSelect};
+    ///
+    /// let index = RankSelect::new(boolvec![true, false, false, true]);
+    ///
+    /// assert_eq!(index.rank0(3), 2);
+    /// ```
+    pub fn rank0(&self, index: usize) -> usize {
+        index - self.rank1(index)
+    }
+
+    /// Returns the index of the `true` value coming after `n` other `true` values, counting from zero.
+    /// Returns None if there are `n` or fewer `true` values.
+    ///
+    /// The superblock and the block holding it are found with binary searches,
+    /// then the `true` values of up to 8 words are counted.
+    /// ```rust
+    /// use bool_vec::{boolvec, RankSelect};
+    ///
+    /// let mut bv = boolvec![false; 100_000];
+    /// bv.set(5, true);
+    /// bv.set(70_000, true);
+    ///
+    /// let index = RankSelect::new(bv);
+    ///
+    /// assert_eq!(index.select1(0), Some(5));
+    /// assert_eq!(index.select1(1), Some(70_000));
+    /// assert_eq!(index.select1(2), None);
+    /// ```
+    pub fn select1(&self, n: usize) -> Option<usize> {
+        if n >= self.ones {
+            return None;
+        }
+
+        Some(self.select(n, true))
+    }
+
+    /// Returns the index of the `false` value coming after `n` other `false` values, counting from zero.
+    /// Returns None if there are `n` or fewer `false` values.
+    ///
+    /// Just like RankSelect::select1(), this takes logarithmic time.
+    /// ```rust
+    /// use bool_vec::{boolvec, RankSelect};
+    ///
+    /// let index = RankSelect::new(boolvec![true, false, true, false]);
+    ///
+    /// assert_eq!(index.select0(1), Some(3));
+    /// assert_eq!(index.select0(2), None);
+    /// ```
+    pub fn select0(&self, n: usize) -> Option<usize> {
+        if n >= self.bits.len() - self.ones {
+            return None;
+        }
+
+        Some(self.select(n, false))
+    }
+
+    /// Moves the indexed BoolVec out, dropping the counts
+    pub fn into_bool_vec(self) -> BoolVec {
+        self.bits
+    }
+
+    /// Returns the index of the value equal to `value` coming after `n` others,
+    /// which must exist
+    fn select(&self, n: usize, value: bool) -> usize {
+        // Number of values equal to `value` among the first `len` ones, given the number of true values
+        let matching = |len: usize, ones: usize| if value { ones } else { len - ones };
+
+        let superblock = last_at_most(0..self.superblocks.len(), n, |superblock| {
+            matching(superblock * SUPERBLOCK_LEN, self.superblocks[superblock])
+        });
+
+        let superblock_ones = self.superblocks[superblock];
+        let block_matching = |block: usize| {
+            matching(
+                block * BLOCK_LEN,
+                superblock_ones + self.blocks[block] as usize,
+            )
+        };
+
+        let first_block = superblock * SUPERBLOCK_BLOCKS;
+        let blocks = first_block..(first_block + SUPERBLOCK_BLOCKS).min(self.blocks.len());
+
+        let block = last_at_most(blocks, n, block_matching);
+
+        let mut remaining = n - block_matching(block);
+
+        for (word_index, &word) in self.bits.as_raw_slice()[block * BLOCK_WORDS..]
+            .iter()
+            .enumerate()
+        {
+            let word = self.bits.msb0_word(word);
+            let word = if value { word } else { !word };
+            let count = word.count_ones() as usize;
+
+            if remaining < count {
+                return (block * BLOCK_WORDS + word_index) * 64 + select_in_word(word, remaining);
+            }
+
+            remaining -= count;
+        }
+
+        unreachable!("the value should have been found")
+    }
+}
+
+/// Binary searches `range` for the last index whose count, given by `count`, is at most `n`.
+/// Counts must be increasing, and the count of the first index must be at most `n`
+fn last_at_most<F: Fn(usize) -> usize>(range: Range<usize>, n: usize, count: F) -> usize {
+    let (mut low, mut high) = (range.start, range.end);
+
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+
+        if count(mid) <= n {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
+/// Returns the position of the set bit coming after `n` other set bits of `word`,
+/// counting from the most significant one
+fn select_in_word(word: u64, n: usize) -> usize {
+    let mut word = word.reverse_bits();
+
+    for _ in 0..n {
+        word &= word - 1;
+    }
+
+    word.trailing_zeros() as usize
+}
+
+impl Deref for RankSelect {
+    type Target = BoolVec;
+
+    fn deref(&self) -> &BoolVec {
+        &self.bits
+    }
+}
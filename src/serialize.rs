@@ -0,0 +1,186 @@
+//! Serialization of BoolVec and BoolSlice with serde, only available with the `serde` feature.
+//!
+//! Human readable formats, like JSON or TOML, serialize values as a string of ones and zeros,
+//! just like Display prints them, so that they can be edited by hand.
+//! Underscores and spaces are allowed between values when deserializing, just like with FromStr.
+//! ```rust
+//! use bool_vec::{boolvec, BoolVec};
+//!
+//! let bv = boolvec![true, false, true, true, false, false, false, false, true];
+//!
+//! let json = serde_json::to_string(&bv).unwrap();
+//!
+//! assert_eq!(json, "\"101100001\"");
+//! assert_eq!(serde_json::from_str::<BoolVec>(&json).unwrap(), bv);
+//! assert_eq!(serde_json::from_str::<BoolVec>("\"1011_0000 1\"").unwrap(), bv);
+//!
+//! // Slices are serialized just like the BoolVec they would be copied into
+//! assert_eq!(serde_json::to_string(&bv.slice(1..4)).unwrap(), "\"011\"");
+//!
+//! assert!(serde_json::from_str::<BoolVec>("\"10x\"").is_err());
+//! ```
+//!
+//! Compact binary formats serialize values as a tuple holding their number and the packed bytes storing them,
+//! most significant bit first whatever the bit order of the BoolVec, just like BoolSlice::bytes() yields them.
+//!
+//! There are always just enough bytes to hold the values, and bits of the last byte past the last value are always zero.
+//! Deserializing bytes where that's not the case fails, so that each BoolVec has a single serialized form.
+//! ```rust
+//! use bool_vec::{boolvec, BoolVec};
+//!
+//! let bv = boolvec![true; 200];
+//!
+//! let mut bytes = bincode::serialize(&bv).unwrap();
+//!
+//! // The number of values and the number of bytes take 8 bytes each
+//! assert_eq!(bytes.len(), 8 + 8 + 25);
+//! assert_eq!(bincode::deserialize::<BoolVec>(&bytes).unwrap(), bv);
+//!
+//! // One byte too many
+//! let too_long = bincode::serialize(&(200usize, vec![u8::MAX; 26])).unwrap();
+//! assert!(bincode::deserialize::<BoolVec>(&too_long).is_err());
+//!
+//! // With 199 values, the last bit of the last byte isn't a value anymore but is still set
+//! bytes[0] = 199;
+//! assert!(bincode::deserialize::<BoolVec>(&bytes).is_err());
+//! ```
+
+use std::fmt::Formatter;
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{bytes_for, Allocator, BoolSlice, BoolVec};
+
+/// Serializes the number of values followed by the packed bytes storing them
+fn serialize_packed<S: Serializer>(
+    len: usize,
+    bytes: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(2)?;
+
+    tuple.serialize_element(&len)?;
+    tuple.serialize_element(&Bytes(bytes))?;
+
+    tuple.end()
+}
+
+/// Packed bytes, serialized as a byte string instead of a sequence of numbers where the format allows it
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Owned packed bytes, deserialized either from a byte string or from a sequence of numbers
+struct ByteBuf(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ByteBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(ByteBufVisitor)
+    }
+}
+
+struct ByteBufVisitor;
+
+impl<'de> Visitor<'de> for ByteBufVisitor {
+    type Value = ByteBuf;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("packed bytes")
+    }
+
+    fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<ByteBuf, E> {
+        Ok(ByteBuf(bytes.to_vec()))
+    }
+
+    fn visit_byte_buf<E: Error>(self, bytes: Vec<u8>) -> Result<ByteBuf, E> {
+        Ok(ByteBuf(bytes))
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<ByteBuf, S::Error> {
+        // The hint comes from the input, and is capped just like serde does it,
+        // so that a corrupted length can't allocate more than the input holds
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+
+        Ok(ByteBuf(bytes))
+    }
+}
+
+impl Serialize for BoolSlice<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+
+        serialize_packed(self.len(), &self.bytes().collect::<Vec<u8>>(), serializer)
+    }
+}
+
+impl<A: Allocator> Serialize for BoolVec<A> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+
+        serialize_packed(self.len(), &self.msb0_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BoolVec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BoolVecVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, BoolVecVisitor)
+        }
+    }
+}
+
+struct BoolVecVisitor;
+
+impl<'de> Visitor<'de> for BoolVecVisitor {
+    type Value = BoolVec;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a string of ones and zeros, or a number of values followed by the packed bytes storing them")
+    }
+
+    fn visit_str<E: Error>(self, s: &str) -> Result<BoolVec, E> {
+        s.parse().map_err(Error::custom)
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<BoolVec, S::Error> {
+        let len: usize = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+
+        let ByteBuf(bytes) = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+        let byte_len = bytes_for(len);
+
+        if bytes.len() != byte_len {
+            let expected = format!("{byte_len} bytes holding {len} values");
+
+            return Err(Error::invalid_length(bytes.len(), &expected.as_str()));
+        }
+
+        let bool_vec = BoolVec::from_bytes(&bytes, len).expect("bytes should hold every value");
+
+        if bool_vec.as_bytes().last() != bytes.last() {
+            return Err(Error::custom("bits past the last value should be zero"));
+        }
+
+        Ok(bool_vec)
+    }
+}
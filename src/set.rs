@@ -0,0 +1,489 @@
+//! A set of small integers, stored as the indexes of the true values of a BoolVec.
+
+use std::fmt::Debug;
+use std::iter::FusedIterator;
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Range, Sub, SubAssign,
+};
+
+use crate::{BoolSlice, BoolVec, LengthPolicy};
+
+/// A set of integers, each one stored as a single bit in a BoolVec which grows as needed.
+///
+/// Each integer is a member if the value at that index of the BoolVec is `true`,
+/// so membership checks are a single bit read and sets are combined 64 members at a time.
+/// Memory grows with the greatest member rather than the number of members,
+/// which makes BoolSet a good fit for dense sets of small integers.
+/// ```rust
+/// use bool_vec::BoolSet;
+///
+/// let mut set = BoolSet::new();
+///
+/// assert!(set.insert(3));
+/// assert!(set.insert(1000));
+/// assert!(!set.insert(3));
+///
+/// assert_eq!(set.len(), 2);
+/// assert!(set.contains(1000));
+/// assert!(!set.contains(999));
+///
+/// let other: BoolSet = [3, 4].into_iter().collect();
+///
+/// assert_eq!((&set & &other).iter().collect::<Vec<_>>(), vec![3]);
+/// assert_eq!((&set | &other).iter().collect::<Vec<_>>(), vec![3, 4, 1000]);
+/// ```
+#[derive(Clone, Default)]
+pub struct BoolSet {
+    bits: BoolVec,
+
+    /// Number of members, so that BoolSet::len() doesn't need to count them
+    len: usize,
+}
+
+/// Iterator over the members of a BoolSet in increasing order, check BoolSet::iter() docs for more infos
+pub struct BoolSetIter<'a> {
+    bits: BoolSlice<'a>,
+
+    /// Index from which the next member is searched
+    counter: usize,
+
+    /// Number of members left
+    remaining: usize,
+}
+
+impl BoolSet {
+    /// Create empty BoolSet, without allocating until a member greater than 127 is inserted
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let set = BoolSet::new();
+    ///
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create empty BoolSet with enough room for members lower than `capacity` without reallocating
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let set = BoolSet::with_capacity(1000);
+    ///
+    /// assert!(set.capacity() >= 1000);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bits: BoolVec::with_capacity(capacity),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of members the BoolSet can hold without reallocating,
+    /// which are the integers lower than the capacity
+    pub fn capacity(&self) -> usize {
+        self.bits.capacity()
+    }
+
+    /// Adds `value` to the set, growing the underlying BoolVec if needed.
+    /// Returns true if `value` wasn't already a member
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let mut set = BoolSet::new();
+    ///
+    /// assert!(set.insert(5));
+    /// assert!(!set.insert(5));
+    /// ```
+    pub fn insert(&mut self, value: usize) -> bool {
+        if self.contains(value) {
+            return false;
+        }
+
+        self.bits.set_or_grow(value, true);
+        self.len += 1;
+
+        true
+    }
+
+    /// Removes `value` from the set. Returns true if it was a member
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let mut set: BoolSet = [1, 2].into_iter().collect();
+    ///
+    /// assert!(set.remove(1));
+    /// assert!(!set.remove(1));
+    /// assert!(!set.remove(1000));
+    /// ```
+    pub fn remove(&mut self, value: usize) -> bool {
+        if !self.contains(value) {
+            return false;
+        }
+
+        self.bits.set(value, false);
+        self.len -= 1;
+
+        true
+    }
+
+    /// Returns true if `value` is a member of the set
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let set: BoolSet = [1, 2].into_iter().collect();
+    ///
+    /// assert!(set.contains(2));
+    /// assert!(!set.contains(3));
+    /// ```
+    pub fn contains(&self, value: usize) -> bool {
+        self.bits.get(value).unwrap_or(false)
+    }
+
+    /// Adds every integer in `range` to the set, growing the underlying BoolVec if needed.
+    /// Returns the number of integers that weren't already members.
+    ///
+    /// Whole bytes are filled at once, just like with BoolSliceMut::fill().
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let mut set: BoolSet = [5].into_iter().collect();
+    ///
+    /// assert_eq!(set.insert_range(3..10), 6);
+    /// assert_eq!(set.len(), 7);
+    /// assert!(set.contains_range(3..10));
+    /// ```
+    pub fn insert_range(&mut self, range: Range<usize>) -> usize {
+        if range.is_empty() {
+            return 0;
+        }
+
+        if range.end > self.bits.len() {
+            self.bits.resize(range.end, false);
+        }
+
+        let mut values = self.bits.slice_mut(range);
+        let inserted = values.count_zeros();
+
+        values.fill(true);
+        self.len += inserted;
+
+        inserted
+    }
+
+    /// Removes every integer in `range` from the set. Returns the number of integers that were members.
+    ///
+    /// Whole bytes are cleared at once, just like with BoolSliceMut::fill().
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let mut set: BoolSet = [1, 5, 7, 500].into_iter().collect();
+    ///
+    /// assert_eq!(set.remove_range(2..1000), 3);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![1]);
+    /// ```
+    pub fn remove_range(&mut self, range: Range<usize>) -> usize {
+        let end = range.end.min(self.bits.len());
+
+        if range.start >= end {
+            return 0;
+        }
+
+        let mut values = self.bits.slice_mut(range.start..end);
+        let removed = values.count_ones();
+
+        values.fill(false);
+        self.len -= removed;
+
+        removed
+    }
+
+    /// Returns true if every integer in `range` is a member of the set, which is always the case for an empty range.
+    /// Bytes full of members are skipped at once
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let mut set = BoolSet::new();
+    /// set.insert_range(10..20);
+    ///
+    /// assert!(set.contains_range(12..15));
+    /// assert!(!set.contains_range(5..15));
+    /// assert!(!set.contains_range(15..25));
+    /// ```
+    pub fn contains_range(&self, range: Range<usize>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+
+        if range.end > self.bits.len() {
+            return false;
+        }
+
+        let len = range.len();
+
+        self.bits.slice(range).find_from(0, false) == len
+    }
+
+    /// Returns the number of members of the set
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let set: BoolSet = [1, 2, 2, 500].into_iter().collect();
+    ///
+    /// assert_eq!(set.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the set has no members
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes every member of the set, keeping the allocated memory
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let mut set: BoolSet = [1, 2].into_iter().collect();
+    ///
+    /// set.clear();
+    ///
+    /// assert!(set.is_empty());
+    /// assert!(!set.contains(1));
+    /// ```
+    pub fn clear(&mut self) {
+        self.bits.clear();
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the members of the set, in increasing order.
+    /// Bytes without members are skipped at once
+    /// ```rust
+    /// use bool_vec::BoolSet;
+    ///
+    /// let set: BoolSet = [500, 2, 1].into_iter().collect();
+    ///
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![1, 2, 500]);
+    /// ```
+    pub fn iter(&self) -> BoolSetIter<'_> {
+        BoolSetIter {
+            bits: self.bits.as_slice(),
+            counter: 0,
+            remaining: self.len,
+        }
+    }
+
+    /// Returns the BoolVec storing the set, where the value at each index is `true` if that index is a member
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolSet};
+    ///
+    /// let set: BoolSet = [0, 2].into_iter().collect();
+    ///
+    /// assert_eq!(set.as_bool_vec(), &boolvec![true, false, true]);
+    /// ```
+    pub fn as_bool_vec(&self) -> &BoolVec {
+        &self.bits
+    }
+
+    /// Moves the BoolVec storing the set out of it, check BoolSet::as_bool_vec() docs for more infos
+    pub fn into_bool_vec(self) -> BoolVec {
+        self.bits
+    }
+
+    /// Combines `self` and `other` with one of the set operations of BoolVec, then recounts the members
+    fn combine_with(&mut self, other: &BoolSet, op: fn(&mut BoolVec, &BoolVec, LengthPolicy)) {
+        op(&mut self.bits, &other.bits, LengthPolicy::ZeroExtend);
+        self.len = self.bits.count_ones();
+    }
+}
+
+impl From<BoolVec> for BoolSet {
+    /// Create BoolSet whose members are the indexes of the `true` values of `bits`
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolSet};
+    ///
+    /// let set = BoolSet::from(boolvec![false, true, true]);
+    ///
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![1, 2]);
+    /// ```
+    fn from(bits: BoolVec) -> Self {
+        Self {
+            len: bits.count_ones(),
+            bits,
+        }
+    }
+}
+
+impl PartialEq for BoolSet {
+    /// Two BoolSets are equal if they have the same members, whatever the length of their BoolVecs
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolSet};
+    ///
+    /// let set1 = BoolSet::from(boolvec![true, false]);
+    /// let set2 = BoolSet::from(boolvec![true, false, false, false]);
+    ///
+    /// assert_eq!(set1, set2);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.bits.differences(&other.bits).next().is_none()
+    }
+}
+
+impl Eq for BoolSet {}
+
+impl Debug for BoolSet {
+    /// Prints the members of the set, like `{1, 2, 500}`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for BoolSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+
+        set
+    }
+}
+
+impl Extend<usize> for BoolSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a BoolSet {
+    type Item = usize;
+
+    type IntoIter = BoolSetIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> Iterator for BoolSetIter<'a> {
+    type Item = usize;
+
+    /// Advances the iterator and returns the next member.
+    /// Returns None when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let member = self.bits.find_from(self.counter, true);
+
+        self.counter = member + 1;
+        self.remaining -= 1;
+
+        Some(member)
+    }
+
+    /// Returns the exact number of members left in the iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for BoolSetIter<'a> {}
+
+impl<'a> FusedIterator for BoolSetIter<'a> {}
+
+macro_rules! impl_set_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $set_op:ident, $doc:literal) => {
+        impl $trait<&BoolSet> for &BoolSet {
+            type Output = BoolSet;
+
+            #[doc = $doc]
+            fn $method(self, rhs: &BoolSet) -> Self::Output {
+                let mut result = self.clone();
+                result.$assign_method(rhs);
+
+                result
+            }
+        }
+
+        impl $assign_trait<&BoolSet> for BoolSet {
+            #[doc = $doc]
+            fn $assign_method(&mut self, rhs: &BoolSet) {
+                self.combine_with(rhs, |bits, other, policy| {
+                    bits.$set_op(other, policy)
+                        .expect("zero extending never fails")
+                });
+            }
+        }
+    };
+}
+
+impl_set_op!(
+    BitOr,
+    bitor,
+    BitOrAssign,
+    bitor_assign,
+    union_with,
+    "Returns the union of two BoolSets, holding the members of either one.
+```rust
+use bool_vec::BoolSet;
+
+let set1: BoolSet = [1, 2].into_iter().collect();
+let set2: BoolSet = [2, 300].into_iter().collect();
+
+assert_eq!(&set1 | &set2, [1, 2, 300].into_iter().collect());
+```"
+);
+
+impl_set_op!(
+    BitAnd,
+    bitand,
+    BitAndAssign,
+    bitand_assign,
+    intersect_with,
+    "Returns the intersection of two BoolSets, holding the members of both.
+```rust
+use bool_vec::BoolSet;
+
+let set1: BoolSet = [1, 2].into_iter().collect();
+let set2: BoolSet = [2, 300].into_iter().collect();
+
+assert_eq!(&set1 & &set2, [2].into_iter().collect());
+```"
+);
+
+impl_set_op!(
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    symmetric_difference_with,
+    "Returns the symmetric difference of two BoolSets, holding the members of exactly one of them.
+```rust
+use bool_vec::BoolSet;
+
+let set1: BoolSet = [1, 2].into_iter().collect();
+let set2: BoolSet = [2, 300].into_iter().collect();
+
+assert_eq!(&set1 ^ &set2, [1, 300].into_iter().collect());
+```"
+);
+
+impl_set_op!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    difference_with,
+    "Returns the difference of two BoolSets, holding the members of the first one which aren't members of the second.
+```rust
+use bool_vec::BoolSet;
+
+let set1: BoolSet = [1, 2].into_iter().collect();
+let set2: BoolSet = [2, 300].into_iter().collect();
+
+assert_eq!(&set1 - &set2, [1].into_iter().collect());
+```"
+);
@@ -0,0 +1,179 @@
+//! SIMD versions of the bulk operations, only available with the `simd` feature.
+//!
+//! Each function checks at runtime whether the CPU supports the required instructions,
+//! returning None otherwise, so that callers can fall back to their scalar version.
+//! Only AVX2 on x86_64 is supported for now.
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// SIMD version of crate::count_ones_bytes(), 32 bytes at a time
+#[cfg_attr(not(target_arch = "x86_64"), allow(unused_variables))]
+pub(crate) fn count_ones_bytes(bytes: &[u8]) -> Option<usize> {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was just checked
+        return Some(unsafe { count_ones_avx2(bytes) });
+    }
+
+    None
+}
+
+/// SIMD version of crate::apply_words(), 4 words at a time.
+/// Returns false without touching `lhs` if the CPU doesn't support it
+#[cfg_attr(not(target_arch = "x86_64"), allow(unused_variables))]
+pub(crate) fn apply_words<F: Fn(u64, u64) -> u64>(lhs: &mut [u64], rhs: &[u64], op: F) -> bool {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was just checked
+        unsafe { apply_words_avx2(lhs, rhs, op) };
+        return true;
+    }
+
+    false
+}
+
+/// Returns whether `lhs` and `rhs` hold the same words, comparing 16 words at a time
+#[cfg_attr(not(target_arch = "x86_64"), allow(unused_variables))]
+pub(crate) fn eq_words(lhs: &[u64], rhs: &[u64]) -> Option<bool> {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was just checked
+        return Some(unsafe { eq_words_avx2(lhs, rhs) });
+    }
+
+    None
+}
+
+/// Returns how many bytes at the start of `bytes` are equal to `skipped`, 32 bytes at a time
+#[cfg_attr(not(target_arch = "x86_64"), allow(unused_variables))]
+pub(crate) fn skip_bytes(bytes: &[u8], skipped: u8) -> Option<usize> {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was just checked
+        return Some(unsafe { skip_bytes_avx2(bytes, skipped) });
+    }
+
+    None
+}
+
+/// Counts the ones of each byte by looking up the counts of its two halves in a table,
+/// then sums the counts of each group of 8 bytes into a 64 bit lane
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn count_ones_avx2(bytes: &[u8]) -> usize {
+    let chunks = bytes.chunks_exact(32);
+    let remainder = chunks.remainder();
+
+    // SAFETY: the caller guarantees that AVX2 is supported, and every load reads a whole chunk
+    let ones = unsafe {
+        #[rustfmt::skip]
+        let table = _mm256_setr_epi8(
+            0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+            0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+        );
+        let low_mask = _mm256_set1_epi8(0x0f);
+        let mut total = _mm256_setzero_si256();
+
+        for chunk in chunks {
+            let bytes = _mm256_loadu_si256(chunk.as_ptr().cast());
+
+            let low = _mm256_and_si256(bytes, low_mask);
+            let high = _mm256_and_si256(_mm256_srli_epi16::<4>(bytes), low_mask);
+
+            let counts = _mm256_add_epi8(
+                _mm256_shuffle_epi8(table, low),
+                _mm256_shuffle_epi8(table, high),
+            );
+
+            total = _mm256_add_epi64(total, _mm256_sad_epu8(counts, _mm256_setzero_si256()));
+        }
+
+        let mut lanes = [0u64; 4];
+        _mm256_storeu_si256(lanes.as_mut_ptr().cast(), total);
+
+        lanes.iter().sum::<u64>() as usize
+    };
+
+    ones + remainder
+        .iter()
+        .map(|byte| byte.count_ones() as usize)
+        .sum::<usize>()
+}
+
+/// Lets the compiler vectorize the loop with AVX2, which works for any bitwise `op`
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn apply_words_avx2<F: Fn(u64, u64) -> u64>(lhs: &mut [u64], rhs: &[u64], op: F) {
+    for (a, b) in lhs.iter_mut().zip(rhs) {
+        *a = op(*a, *b);
+    }
+}
+
+/// ORs together the XOR of 4 pairs of vectors, so that a single test is needed for 16 words
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn eq_words_avx2(lhs: &[u64], rhs: &[u64]) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+
+    let lhs_chunks = lhs.chunks_exact(16);
+    let rhs_chunks = rhs.chunks_exact(16);
+
+    if lhs_chunks.remainder() != rhs_chunks.remainder() {
+        return false;
+    }
+
+    for (a, b) in lhs_chunks.zip(rhs_chunks) {
+        // SAFETY: the caller guarantees that AVX2 is supported, and every load reads 4 words of a chunk
+        let equal = unsafe {
+            let mut diff = _mm256_setzero_si256();
+
+            for offset in (0..16).step_by(4) {
+                let a = _mm256_loadu_si256(a[offset..].as_ptr().cast());
+                let b = _mm256_loadu_si256(b[offset..].as_ptr().cast());
+
+                diff = _mm256_or_si256(diff, _mm256_xor_si256(a, b));
+            }
+
+            _mm256_testz_si256(diff, diff) == 1
+        };
+
+        if !equal {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Compares 32 bytes at a time with `skipped`, stopping at the first chunk holding a different byte
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn skip_bytes_avx2(bytes: &[u8], skipped: u8) -> usize {
+    let mut skipped_len = 0;
+
+    for chunk in bytes.chunks_exact(32) {
+        // SAFETY: the caller guarantees that AVX2 is supported, and every load reads a whole chunk
+        let equal_mask = unsafe {
+            let chunk = _mm256_loadu_si256(chunk.as_ptr().cast());
+            let equal = _mm256_cmpeq_epi8(chunk, _mm256_set1_epi8(skipped as i8));
+
+            _mm256_movemask_epi8(equal) as u32
+        };
+
+        // Each bit of the mask tells whether the byte at that position is equal to `skipped`
+        if equal_mask != u32::MAX {
+            return skipped_len + equal_mask.trailing_ones() as usize;
+        }
+
+        skipped_len += 32;
+    }
+
+    skipped_len
+        + bytes[skipped_len..]
+            .iter()
+            .take_while(|&&byte| byte == skipped)
+            .count()
+}
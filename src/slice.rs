@@ -0,0 +1,3165 @@
+//! Borrowed views over the values of a BoolVec.
+
+use std::fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex, Write};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::marker::PhantomData;
+use std::ops::{
+    Index, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::{bytes_for, checked_range_to_indexes, count_ones_bytes, fill_bytes};
+use crate::{
+    combine_bytes, gather_bits, scatter_bits, truth_table, Allocator, BitMut, BitOrder,
+    BoolVecError,
+};
+use crate::{fmt_bytes, range_to_indexes};
+use crate::{BoolIndex, BoolVec, BoolVecIter, Bytes, Chunks, ChunksU64, Differences, Runs};
+use crate::{DisplayWith, FormatOptions, HEADER_MAGIC, HEADER_VERSION};
+use crate::{Windows, WindowsU64};
+
+/// A borrowed view over a range of values of a BoolVec, just like `&[bool]` is for `Vec<bool>`.
+/// The view doesn't need to start on a byte boundary, and is as cheap to copy as a reference:
+/// ```rust
+/// use bool_vec::{boolvec, BoolSlice};
+///
+/// fn count_trues(slice: BoolSlice<'_>) -> usize {
+///     slice.count_ones()
+/// }
+///
+/// let bv = boolvec![true, false, true, true, false, true, true, true, true, true];
+///
+/// assert_eq!(count_trues(bv.as_slice()), 8);
+/// assert_eq!(count_trues(bv.slice(1..5)), 2);
+///
+/// // Just like with slices, single values can be indexed
+/// assert_eq!(bv[1], false);
+/// assert!(bv.slice(1..5)[1]);
+/// ```
+///
+/// Methods working on another BoolSlice, like BoolSlice::and_count(), also take a `&BoolVec`:
+/// ```rust
+/// use bool_vec::boolvec;
+///
+/// let bv1 = boolvec![true, true, false, true];
+/// let bv2 = boolvec![true, false, true, true];
+///
+/// assert_eq!(bv1.and_count(&bv2), 2);
+/// assert_eq!(bv1.and_count(bv2.slice(1..)), 1);
+/// ```
+///
+/// Debug, Display, Binary, octal and hexadecimal printing work just like with a BoolVec.
+/// Binary, octal and hexadecimal printing show the bytes the values would have in a BoolVec
+/// with the same bit order:
+/// ```rust
+/// use bool_vec::boolvec;
+///
+/// let bv = boolvec![true, false, true, true, false, false, false, false, true];
+///
+/// let slice = bv.slice(2..);
+///
+/// assert_eq!(format!("{slice:?}"), "[true, true, false, false, false, false, true]");
+/// assert_eq!(format!("{slice:b}"), "[11000010]");
+/// assert_eq!(format!("{slice:x}"), "[c2]");
+/// ```
+#[derive(Clone, Copy)]
+pub struct BoolSlice<'a> {
+    /// Pointer to the first byte holding values of the slice. It comes from a borrow
+    /// of every byte holding values, so that all of them can be accessed through it
+    ptr: NonNull<u8>,
+
+    /// Index of the first value inside the first byte, always lower than 8
+    head: usize,
+    len: usize,
+
+    /// Order in which the values are packed into bytes
+    order: BitOrder,
+
+    /// True if the pointer comes from a mutable borrow, whose edge bytes may be shared
+    /// with another BoolSliceMut after a split, so that they must be read atomically.
+    /// Other pointers only grant read access, so edge bytes can't be read atomically through them
+    shared_edges: bool,
+    marker: PhantomData<&'a [u8]>,
+}
+
+/// A mutable view over a range of values of a BoolVec, just like `&mut [bool]` is for `Vec<bool>`.
+/// It allows changing the values it views, without touching the ones around it:
+/// ```rust
+/// use bool_vec::{boolvec, BoolSliceMut};
+///
+/// fn clear_frame(mut frame: BoolSliceMut<'_>) {
+///     frame.fill(false);
+/// }
+///
+/// let mut bv = boolvec![true; 10];
+///
+/// clear_frame(bv.slice_mut(2..5));
+///
+/// assert_eq!(bv, boolvec![true, true, false, false, false, true, true, true, true, true]);
+/// ```
+///
+/// Values can be read just like with a BoolSlice, check BoolSlice docs for more infos
+pub struct BoolSliceMut<'a> {
+    /// The values viewed, whose pointer comes from a mutable borrow so that bytes can be written through it.
+    /// Never handed out, since it could outlive the mutable borrow
+    slice: BoolSlice<'a>,
+    marker: PhantomData<&'a mut [u8]>,
+}
+
+// SAFETY: a BoolSlice only ever reads its bytes, just like a &[u8] does.
+// Its edge bytes may be written by a BoolSliceMut on another thread after a split, but only atomically
+unsafe impl Send for BoolSlice<'_> {}
+unsafe impl Sync for BoolSlice<'_> {}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Types that can be used to get values out of a BoolSlice with BoolSlice::get(),
+/// just like `std::slice::SliceIndex`.
+/// A `usize` gets a single value, while ranges get a BoolSlice
+pub trait BoolSliceIndex: private::Sealed {
+    /// The type returned when getting values through this index
+    type Output<'a>;
+
+    /// Returns the values at this index, or None if it is out of bounds
+    fn get(self, slice: BoolSlice<'_>) -> Option<Self::Output<'_>>;
+}
+
+impl private::Sealed for usize {}
+
+impl BoolSliceIndex for usize {
+    type Output<'a> = bool;
+
+    fn get(self, slice: BoolSlice<'_>) -> Option<bool> {
+        if self >= slice.len() {
+            return None;
+        }
+
+        Some(slice.bit(self))
+    }
+}
+
+/// Values that can be written at the indexes selected by a mask with BoolSliceMut::masked_set().
+/// A `bool` is written at every selected index,
+/// while the values of a BoolSlice or BoolVec are scattered to them in order
+pub trait MaskedSource: private::Sealed {
+    /// Returns the byte to write at the bits selected by `selected`,
+    /// `written` values having already been taken from the source
+    fn scattered(&self, written: usize, selected: u8) -> u8;
+}
+
+impl private::Sealed for bool {}
+
+impl MaskedSource for bool {
+    fn scattered(&self, _written: usize, selected: u8) -> u8 {
+        if *self {
+            selected
+        } else {
+            0
+        }
+    }
+}
+
+impl private::Sealed for BoolSlice<'_> {}
+
+impl MaskedSource for BoolSlice<'_> {
+    /// Values past the end of the BoolSlice are read as `false`
+    fn scattered(&self, written: usize, selected: u8) -> u8 {
+        scatter_bits(self.read_byte(written), selected)
+    }
+}
+
+impl<A: Allocator> private::Sealed for &BoolVec<A> {}
+
+impl<A: Allocator> MaskedSource for &BoolVec<A> {
+    fn scattered(&self, written: usize, selected: u8) -> u8 {
+        self.as_slice().scattered(written, selected)
+    }
+}
+
+/// Values referenced when indexing a single value, since packed bits can't be referenced directly
+static TRUE: bool = true;
+static FALSE: bool = false;
+
+impl Index<usize> for BoolSlice<'_> {
+    type Output = bool;
+
+    /// Returns a reference to the value at `int_index`.
+    ///
+    /// Just like with a Vec, this panics if `int_index` overflows BoolSlice.len().
+    /// Use BoolSlice::get() to get None instead
+    fn index(&self, int_index: usize) -> &bool {
+        match self.get(int_index) {
+            Some(true) => &TRUE,
+            Some(false) => &FALSE,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {int_index}",
+                self.len()
+            ),
+        }
+    }
+}
+
+impl Index<usize> for BoolSliceMut<'_> {
+    type Output = bool;
+
+    fn index(&self, int_index: usize) -> &bool {
+        if self.as_slice()[int_index] {
+            &TRUE
+        } else {
+            &FALSE
+        }
+    }
+}
+
+impl<A: Allocator> Index<usize> for BoolVec<A> {
+    type Output = bool;
+
+    fn index(&self, int_index: usize) -> &bool {
+        if self.as_slice()[int_index] {
+            &TRUE
+        } else {
+            &FALSE
+        }
+    }
+}
+
+/// Implements BoolSliceIndex for every range type
+macro_rules! impl_range_index {
+    ($($range:ty),*) => {$(
+        impl private::Sealed for $range {}
+
+        impl BoolSliceIndex for $range {
+            type Output<'a> = BoolSlice<'a>;
+
+            fn get(self, slice: BoolSlice<'_>) -> Option<BoolSlice<'_>> {
+                let (start, end) = checked_range_to_indexes(self, slice.len())?;
+
+                Some(slice.slice(start..end))
+            }
+        }
+    )*};
+}
+
+impl_range_index!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>
+);
+
+/// Sequences of values that can be compared against the values of a BoolSlice,
+/// check BoolSlice::starts_with() docs for more infos
+pub trait BoolSequence: private::Sealed {
+    /// Returns the number of values in the sequence
+    fn len(&self) -> usize;
+
+    /// Returns true if the sequence is empty
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the values of the sequence are equal to the ones of `slice`,
+    /// which is as long as the sequence
+    fn matches(&self, slice: BoolSlice<'_>) -> bool;
+}
+
+impl BoolSequence for BoolSlice<'_> {
+    fn len(&self) -> usize {
+        BoolSlice::len(self)
+    }
+
+    /// Values are compared a whole byte at a time
+    fn matches(&self, slice: BoolSlice<'_>) -> bool {
+        *self == slice
+    }
+}
+
+impl<A: Allocator> private::Sealed for BoolVec<A> {}
+
+impl<A: Allocator> BoolSequence for BoolVec<A> {
+    fn len(&self) -> usize {
+        BoolVec::len(self)
+    }
+
+    /// Values are compared a whole byte at a time
+    fn matches(&self, slice: BoolSlice<'_>) -> bool {
+        self.as_slice() == slice
+    }
+}
+
+impl private::Sealed for [bool] {}
+
+impl BoolSequence for [bool] {
+    fn len(&self) -> usize {
+        <[bool]>::len(self)
+    }
+
+    fn matches(&self, slice: BoolSlice<'_>) -> bool {
+        self.iter().copied().eq(slice.iter())
+    }
+}
+
+impl<const N: usize> private::Sealed for [bool; N] {}
+
+impl<const N: usize> BoolSequence for [bool; N] {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn matches(&self, slice: BoolSlice<'_>) -> bool {
+        self[..].matches(slice)
+    }
+}
+
+impl private::Sealed for Vec<bool> {}
+
+impl BoolSequence for Vec<bool> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn matches(&self, slice: BoolSlice<'_>) -> bool {
+        self[..].matches(slice)
+    }
+}
+
+impl<'a> BoolSlice<'a> {
+    /// Builds a BoolSlice viewing `len` values stored in `order`, starting from the bit `head` of the byte at `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must come from a borrow of at least `bytes_for(head + len)` bytes, which must stay
+    /// valid for `'a`. The values viewed must not be changed for `'a`.
+    /// `head` must be lower than 8
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: NonNull<u8>,
+        head: usize,
+        len: usize,
+        order: BitOrder,
+    ) -> Self {
+        Self {
+            ptr,
+            head,
+            len,
+            order,
+            shared_edges: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the index of the first value inside the first byte
+    fn head(&self) -> usize {
+        self.head
+    }
+
+    /// Returns the order in which the values of the BoolSlice are packed into bytes,
+    /// which is the one of the BoolVec it views. Check BitOrder docs for more infos
+    /// ```rust
+    /// use bool_vec::{boolvec, BitOrder, BoolSlice};
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv.slice(1..).bit_order(), BitOrder::Msb0);
+    ///
+    /// let slice = BoolSlice::from_bytes_with_order(&[0b0000_0101], 3, BitOrder::Lsb0).unwrap();
+    ///
+    /// assert_eq!(slice.bit_order(), BitOrder::Lsb0);
+    /// assert_eq!(slice, bv);
+    /// ```
+    pub fn bit_order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Returns the number of bytes holding values of the slice
+    fn byte_len(&self) -> usize {
+        bytes_for(self.head() + self.len())
+    }
+
+    /// Returns a pointer to the first byte holding values of the slice
+    fn byte_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns true if the byte at `byte_index` is the first or the last one of the slice.
+    /// These bytes may be shared with other slices after a split of a BoolSliceMut,
+    /// so they're only ever accessed atomically through its views
+    fn is_edge_byte(&self, byte_index: usize) -> bool {
+        byte_index == 0 || byte_index + 1 == self.byte_len()
+    }
+
+    /// Returns the byte at `byte_index` most significant bit first, whatever the bit order of the slice,
+    /// including the bits that don't belong to the slice
+    fn load_byte(&self, byte_index: usize) -> u8 {
+        assert!(byte_index < self.byte_len());
+
+        // SAFETY: the byte is inside the slice
+        unsafe { self.load_byte_unchecked(byte_index) }
+    }
+
+    /// Same as BoolSlice::load_byte(), without checking that `byte_index` is inside the slice.
+    ///
+    /// # Safety
+    /// `byte_index` must be lower than BoolSlice::byte_len()
+    unsafe fn load_byte_unchecked(&self, byte_index: usize) -> u8 {
+        // SAFETY: the byte is inside the slice. Edge bytes of a BoolSliceMut may be written by other threads,
+        // but only through atomic operations, and its pointer grants write access as AtomicU8 requires
+        let byte = unsafe {
+            let ptr = self.byte_ptr().add(byte_index);
+
+            if self.shared_edges && self.is_edge_byte(byte_index) {
+                AtomicU8::from_ptr(ptr).load(Ordering::Relaxed)
+            } else {
+                ptr.read()
+            }
+        };
+
+        self.bit_order().convert(byte)
+    }
+
+    /// Returns the bytes between the first and the last one, which only hold values of this slice
+    fn interior_bytes(&self) -> &'a [u8] {
+        let len = self.byte_len().saturating_sub(2);
+
+        // SAFETY: interior bytes are never shared with other slices
+        unsafe { std::slice::from_raw_parts(self.byte_ptr().wrapping_add(1), len) }
+    }
+
+    /// Returns the index of the byte holding the value at `start`,
+    /// along with the index of that value inside the byte
+    fn subrange_head(&self, start: usize) -> (usize, usize) {
+        let index = BoolIndex::from(self.head() + start);
+
+        (index.byte_index, index.bit_index as usize)
+    }
+
+    /// Returns a BoolSlice viewing the values from `start` (included) to `end` (excluded),
+    /// which must not be greater than BoolSlice.len(). Its pointer is derived from the one of this slice,
+    /// so that it can access the same bytes
+    fn subslice(&self, start: usize, end: usize) -> BoolSlice<'a> {
+        let (byte_index, head) = self.subrange_head(start);
+
+        // SAFETY: the byte holding the value at `start` is at most one past the last byte of the slice
+        let ptr = unsafe { self.ptr.add(byte_index) };
+
+        BoolSlice {
+            ptr,
+            head,
+            len: end - start,
+            ..*self
+        }
+    }
+
+    /// Reads the `len` values starting at `start` as the lowest bits of a u64, a whole byte at a time.
+    /// `len` must not be greater than 64. Values past BoolSlice.len() are read as zero
+    pub(crate) fn read_u64(&self, start: usize, len: usize) -> u64 {
+        let mut value = 0u64;
+        let mut read = 0;
+
+        while read < len {
+            let chunk = (len - read).min(8);
+            let byte = self.read_byte(start + read) >> (8 - chunk);
+
+            value = (value << chunk) | byte as u64;
+            read += chunk;
+        }
+
+        value
+    }
+
+    /// Returns the index of the first value equal to `value` coming at or after `from`,
+    /// or BoolSlice.len() if there is none. Bytes not containing `value` at all are skipped at once
+    pub(crate) fn find_from(&self, from: usize, value: bool) -> usize {
+        let head = self.head();
+        let end = head + self.len();
+        let mut int_index = head + from;
+
+        while int_index < end {
+            let index = BoolIndex::from(int_index);
+
+            let byte = self.load_byte(index.byte_index);
+            let byte = if value { byte } else { !byte };
+
+            // Values coming before int_index must be ignored
+            let byte = byte & (u8::MAX >> index.bit_index);
+
+            if byte != 0 {
+                let found = index.byte_index * 8 + byte.leading_zeros() as usize;
+                return found.min(end) - head;
+            }
+
+            int_index = (index.byte_index + 1) * 8;
+
+            // Interior bytes not containing `value` at all are skipped in bulk
+            #[cfg(feature = "simd")]
+            if index.byte_index + 1 < self.byte_len().saturating_sub(1) {
+                let interior = &self.interior_bytes()[index.byte_index..];
+                let skipped = if value { 0 } else { u8::MAX };
+
+                if let Some(skipped_len) = crate::simd::skip_bytes(interior, skipped) {
+                    int_index += skipped_len * 8;
+                }
+            }
+        }
+
+        self.len()
+    }
+
+    /// Returns the index of the value equal to `value` coming after `n` others,
+    /// skipping whole bytes holding `n` or fewer of them by counting their bits
+    fn select_nth(&self, mut n: usize, value: bool) -> Option<usize> {
+        let head = self.head();
+        let end = head + self.len();
+
+        for byte_index in 0..self.byte_len() {
+            let byte = self.load_byte(byte_index);
+            let byte = if value { byte } else { !byte };
+
+            // Values coming before the head or after the end of the slice must be ignored
+            let first = (byte_index * 8).max(head);
+            let last = (byte_index * 8 + 8).min(end);
+            let mut byte = byte
+                & (u8::MAX >> (first - byte_index * 8))
+                & (u8::MAX << (byte_index * 8 + 8 - last));
+
+            let count = byte.count_ones() as usize;
+
+            if n >= count {
+                n -= count;
+                continue;
+            }
+
+            for _ in 0..n {
+                byte &= !(0x80 >> byte.leading_zeros());
+            }
+
+            return Some(byte_index * 8 + byte.leading_zeros() as usize - head);
+        }
+
+        None
+    }
+
+    /// Combines the values of `self` and `other` with `op` a whole byte at a time,
+    /// returning the results as a new BoolVec as long as the shortest of the two
+    fn combined<F: Fn(u8, u8) -> u8>(&self, other: BoolSlice<'_>, op: F) -> BoolVec {
+        let len = self.len().min(other.len());
+
+        let mut result = BoolVec::with_capacity(len);
+
+        for (byte_index, byte) in result.raw_bytes_mut()[..bytes_for(len)]
+            .iter_mut()
+            .enumerate()
+        {
+            *byte = op(
+                self.read_byte(byte_index * 8),
+                other.read_byte(byte_index * 8),
+            );
+        }
+
+        // Values past the end might have been set by the combination
+        result.length = len;
+        result.clear_unused_bits();
+
+        result
+    }
+
+    /// Combines the first `len` values of `self` and `other` with `op` a whole byte at a time,
+    /// returning the number of `true` values in the results. Values past the end of either one are read as zero
+    fn combined_count<F: Fn(u8, u8) -> u8>(
+        &self,
+        other: BoolSlice<'_>,
+        len: usize,
+        op: F,
+    ) -> usize {
+        (0..len)
+            .step_by(8)
+            .map(|start| op(self.read_byte(start), other.read_byte(start)).count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns the value at `int_index`, which must be lower than BoolSlice.len()
+    fn bit(&self, int_index: usize) -> bool {
+        let index = BoolIndex::from(self.head() + int_index);
+
+        (self.load_byte(index.byte_index) << index.bit_index) & 128 == 128
+    }
+
+    /// Reads the 8 values starting at `start` as a single byte.
+    /// Bits past BoolSlice.len() are read as zero
+    pub(crate) fn read_byte(&self, start: usize) -> u8 {
+        let byte_len = self.byte_len();
+        let index = BoolIndex::from(self.head() + start);
+
+        let load = |byte_index| {
+            if byte_index < byte_len {
+                self.load_byte(byte_index)
+            } else {
+                0
+            }
+        };
+
+        let high = load(index.byte_index);
+        let low = load(index.byte_index + 1);
+
+        let byte = if index.bit_index == 0 {
+            high
+        } else {
+            (high << index.bit_index) | (low >> (8 - index.bit_index))
+        };
+
+        let used = self.len().saturating_sub(start);
+        if used >= 8 {
+            return byte;
+        }
+
+        byte & !(u8::MAX >> used)
+    }
+
+    /// Wraps existing packed bytes into a BoolSlice viewing their first `bit_len` values, without copying them.
+    /// Returns None if `bytes` holds less than `bit_len` values.
+    ///
+    /// Values are read most significant bit first: the value at index 0 is the highest bit
+    /// of the first byte, the value at index 7 is its lowest bit, the value at index 8 is the
+    /// highest bit of the second byte and so on. Bits coming after `bit_len` are ignored.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolSlice};
+    ///
+    /// let packet = [0b1011_0000, 0b1111_1111];
+    ///
+    /// let slice = BoolSlice::from_bytes(&packet, 10).unwrap();
+    ///
+    /// assert_eq!(slice, boolvec![true, false, true, true, false, false, false, false, true, true]);
+    /// assert_eq!(slice.count_ones(), 5);
+    ///
+    /// assert!(BoolSlice::from_bytes(&packet, 17).is_none());
+    /// ```
+    pub fn from_bytes(bytes: &'a [u8], bit_len: usize) -> Option<Self> {
+        Self::from_bytes_with_order(bytes, bit_len, BitOrder::Msb0)
+    }
+
+    /// Same as BoolSlice::from_bytes(), reading the values of each byte in the given order.
+    /// Bytes are still viewed in place, without being copied or converted
+    /// ```rust
+    /// use bool_vec::{boolvec, BitOrder, BoolSlice};
+    ///
+    /// // Arrow validity bitmaps store the first value in the least significant bit
+    /// let validity = [0b1111_0101, 0b0000_0001];
+    ///
+    /// let slice = BoolSlice::from_bytes_with_order(&validity, 9, BitOrder::Lsb0).unwrap();
+    ///
+    /// assert_eq!(slice, boolvec![true, false, true, false, true, true, true, true, true]);
+    /// ```
+    pub fn from_bytes_with_order(bytes: &'a [u8], bit_len: usize, order: BitOrder) -> Option<Self> {
+        if bytes_for(bit_len) > bytes.len() {
+            return None;
+        }
+
+        // SAFETY: bytes holds enough bytes for bit_len values, and is borrowed for the whole lifetime
+        Some(unsafe { BoolSlice::from_raw_parts(NonNull::from(bytes).cast(), 0, bit_len, order) })
+    }
+
+    /// Returns the length of the BoolSlice
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true; 10];
+    ///
+    /// assert_eq!(bv.slice(3..).len(), 7);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the BoolSlice is empty
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true; 10];
+    ///
+    /// assert!(bv.slice(3..3).is_empty());
+    /// assert!(!bv.slice(3..4).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get bool value from a BoolSlice. Returns None if index overflows BoolSlice.len().
+    ///
+    /// Just like with slices, a range can be used instead of an index to get a BoolSlice,
+    /// returning None instead of panicking if the range is invalid
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// let slice = bv.slice(1..);
+    ///
+    /// assert_eq!(slice.get(0), Some(false));
+    /// assert_eq!(slice.get(1), Some(true));
+    /// assert_eq!(slice.get(2), None);
+    ///
+    /// assert_eq!(slice.get(..1), Some(bv.slice(1..2)));
+    /// assert_eq!(slice.get(..3), None);
+    /// ```
+    pub fn get<I: BoolSliceIndex>(&self, index: I) -> Option<I::Output<'a>> {
+        index.get(*self)
+    }
+
+    /// Returns a BoolVecError carrying `int_index` if it overflows BoolSlice.len()
+    fn check_index(&self, int_index: usize) -> Result<(), BoolVecError> {
+        if int_index >= self.len() {
+            return Err(BoolVecError::IndexOutOfBounds {
+                index: int_index,
+                len: self.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Same as BoolSlice::get(), but returns a BoolVecError explaining why the call failed
+    /// if index overflows BoolSlice.len()
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVecError};
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv.try_get(1), Ok(false));
+    /// assert_eq!(bv.try_get(3), Err(BoolVecError::IndexOutOfBounds { index: 3, len: 3 }));
+    /// ```
+    pub fn try_get(&self, int_index: usize) -> Result<bool, BoolVecError> {
+        self.check_index(int_index)?;
+
+        Ok(self.bit(int_index))
+    }
+
+    /// Get bool value from a BoolSlice, without checking that `int_index` is lower than BoolSlice.len()
+    ///
+    /// # Safety
+    /// Calling this method with an index overflowing BoolSlice.len() is undefined behavior,
+    /// even if the value is not used. Use BoolSlice::get() when in doubt
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// for i in 0..bv.len() {
+    ///     // SAFETY: i is always lower than bv.len()
+    ///     let value = unsafe { bv.get_unchecked(i) };
+    ///
+    ///     assert_eq!(value, i != 1);
+    /// }
+    /// ```
+    pub unsafe fn get_unchecked(&self, int_index: usize) -> bool {
+        let index = BoolIndex::from(self.head() + int_index);
+
+        // SAFETY: the caller guarantees that the value is inside the slice
+        let byte = unsafe { self.load_byte_unchecked(index.byte_index) };
+
+        (byte << index.bit_index) & 128 == 128
+    }
+
+    /// Gets the values at all the specified indexes, in the same order.
+    /// Returns None if any index overflows BoolSlice.len().
+    /// Bounds are checked once for all the indexes, before reading any value
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true];
+    ///
+    /// assert_eq!(bv.get_many(&[3, 1, 0]), Some(vec![true, false, true]));
+    /// assert_eq!(bv.get_many(&[0, 4]), None);
+    /// ```
+    pub fn get_many(&self, indexes: &[usize]) -> Option<Vec<bool>> {
+        if indexes.iter().any(|&int_index| int_index >= self.len()) {
+            return None;
+        }
+
+        Some(
+            indexes
+                .iter()
+                .map(|&int_index| self.bit(int_index))
+                .collect(),
+        )
+    }
+
+    /// Returns an iterator over the values of the BoolSlice
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true];
+    ///
+    /// let values: Vec<bool> = bv.slice(1..).iter().collect();
+    ///
+    /// assert_eq!(values, vec![false, true, true]);
+    /// ```
+    pub fn iter(&self) -> BoolVecIter<'a> {
+        BoolVecIter {
+            slice: *self,
+            counter: 0,
+            end: self.len(),
+        }
+    }
+
+    /// Returns the number of true values in the BoolSlice, counting a whole byte at a time.
+    ///
+    /// With the `rayon` feature, huge BoolSlices are counted in parallel.
+    /// With the `simd` feature, bytes are counted 32 at a time on CPUs supporting AVX2.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false, true, true, true, true, true];
+    ///
+    /// assert_eq!(bv.slice(1..4).count_ones(), 2);
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        let byte_len = self.byte_len();
+
+        if byte_len == 0 {
+            return 0;
+        }
+
+        let head = self.head();
+        let tail = (head + self.len()) % 8;
+
+        // Values coming before the head or after the end of the slice must be ignored
+        let first_mask = u8::MAX >> head;
+        let last_mask = if tail == 0 {
+            u8::MAX
+        } else {
+            !(u8::MAX >> tail)
+        };
+
+        let first = self.load_byte(0);
+
+        if byte_len == 1 {
+            return (first & first_mask & last_mask).count_ones() as usize;
+        }
+
+        let last = self.load_byte(byte_len - 1);
+
+        (first & first_mask).count_ones() as usize
+            + count_ones_bytes(self.interior_bytes())
+            + (last & last_mask).count_ones() as usize
+    }
+
+    /// Returns the number of false values in the BoolSlice
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false, true, true, true, true, true];
+    ///
+    /// assert_eq!(bv.slice(1..5).count_zeros(), 2);
+    /// ```
+    pub fn count_zeros(&self) -> usize {
+        self.len() - self.count_ones()
+    }
+
+    /// Returns the index of the true value coming after `n` other true values, counting from zero.
+    /// Returns None if there are `n` or fewer true values.
+    ///
+    /// Bytes are scanned one at a time, skipping the ones holding too few true values by counting them,
+    /// which is much faster than iterating over every value.
+    /// Use RankSelect to answer many such queries on the same values.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![false, true, false, false, true, true, false, true, false, true];
+    ///
+    /// assert_eq!(bv.select_nth_one(0), Some(1));
+    /// assert_eq!(bv.select_nth_one(4), Some(9));
+    /// assert_eq!(bv.select_nth_one(5), None);
+    ///
+    /// // Indexes are relative to the start of the slice
+    /// assert_eq!(bv.slice(2..).select_nth_one(1), Some(3));
+    /// ```
+    pub fn select_nth_one(&self, n: usize) -> Option<usize> {
+        self.select_nth(n, true)
+    }
+
+    /// Returns the index of the false value coming after `n` other false values, counting from zero.
+    /// Returns None if there are `n` or fewer false values.
+    ///
+    /// Check BoolSlice::select_nth_one() docs for more infos
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![false, true, false, false, true, true, false, true, false, true];
+    ///
+    /// assert_eq!(bv.select_nth_zero(2), Some(3));
+    /// assert_eq!(bv.select_nth_zero(5), None);
+    /// ```
+    pub fn select_nth_zero(&self, n: usize) -> Option<usize> {
+        self.select_nth(n, false)
+    }
+
+    /// Returns a BoolSlice viewing the specified range of values of this BoolSlice.
+    ///
+    /// Just like slice indexing, this panics if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the BoolSlice.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false, true];
+    ///
+    /// let slice = bv.slice(1..);
+    ///
+    /// assert_eq!(slice.slice(1..3), boolvec![true, true]);
+    /// ```
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> BoolSlice<'a> {
+        let (start, end) = range_to_indexes(range, self.len());
+
+        self.subslice(start, end)
+    }
+
+    /// Divides the BoolSlice into two at `mid`: the first one holds the values
+    /// from 0 to `mid` (excluded) and the second one the values from `mid` to BoolSlice.len().
+    ///
+    /// Just like with slices, this panics if `mid` is greater than BoolSlice.len()
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false];
+    ///
+    /// let (left, right) = bv.split_at(2);
+    ///
+    /// assert_eq!(left, boolvec![true, false]);
+    /// assert_eq!(right, boolvec![true, true, false]);
+    /// ```
+    pub fn split_at(&self, mid: usize) -> (BoolSlice<'a>, BoolSlice<'a>) {
+        assert!(mid <= self.len(), "mid > len");
+
+        (self.slice(..mid), self.slice(mid..))
+    }
+
+    /// Returns an iterator over `chunk_size` values at a time, starting at the beginning of the BoolSlice.
+    /// Every chunk is returned as a BoolSlice viewing its values.
+    ///
+    /// Chunks do not overlap. If `chunk_size` does not divide BoolSlice.len(),
+    /// the last chunk will be shorter.
+    ///
+    /// Just like `slice::chunks()`, this panics if `chunk_size` is 0.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false];
+    ///
+    /// let mut chunks = bv.chunks(2);
+    ///
+    /// assert_eq!(chunks.next().unwrap(), boolvec![true, false]);
+    /// assert_eq!(chunks.next().unwrap(), boolvec![true, true]);
+    /// assert_eq!(chunks.next().unwrap(), boolvec![false]);
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'a> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        Chunks {
+            slice: *self,
+            chunk_size,
+            counter: 0,
+            end: self.len(),
+        }
+    }
+
+    /// Returns an iterator over all the contiguous windows of length `window_size`.
+    /// Every window is returned as a BoolSlice viewing its values.
+    ///
+    /// Windows overlap. If BoolSlice.len() is less than `window_size`, the iterator returns no values.
+    ///
+    /// Just like `slice::windows()`, this panics if `window_size` is 0.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true];
+    ///
+    /// let mut windows = bv.windows(3);
+    ///
+    /// assert_eq!(windows.next().unwrap(), boolvec![true, false, true]);
+    /// assert_eq!(windows.next().unwrap(), boolvec![false, true, true]);
+    /// assert_eq!(windows.next(), None);
+    /// ```
+    pub fn windows(&self, window_size: usize) -> Windows<'a> {
+        assert!(window_size != 0, "window size must be non-zero");
+
+        Windows {
+            slice: *self,
+            window_size,
+            counter: 0,
+            end: (self.len() + 1).saturating_sub(window_size),
+        }
+    }
+
+    /// Returns an iterator over all the contiguous windows of length `window_size`,
+    /// with every window packed into a u64.
+    ///
+    /// The first value of a window ends up in the most significant of the `window_size` lowest bits,
+    /// so that the window reads like a binary number. Higher bits are always zero.
+    ///
+    /// Panics if `window_size` is 0 or greater than 64.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true];
+    ///
+    /// let windows: Vec<u64> = bv.windows_u64(3).collect();
+    ///
+    /// assert_eq!(windows, vec![0b101, 0b011]);
+    /// ```
+    pub fn windows_u64(&self, window_size: usize) -> WindowsU64<'a> {
+        assert!(
+            (1..=64).contains(&window_size),
+            "window size must be between 1 and 64"
+        );
+
+        WindowsU64 {
+            slice: *self,
+            window_size,
+            counter: 0,
+            end: (self.len() + 1).saturating_sub(window_size),
+        }
+    }
+
+    /// Returns an iterator over every maximal run of equal consecutive values,
+    /// as `(value, start, len)` tuples.
+    ///
+    /// Whole bytes belonging to the same run are skipped at once, so long runs are cheap to find.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, true, false, false, false, true];
+    ///
+    /// let runs: Vec<(bool, usize, usize)> = bv.runs().collect();
+    ///
+    /// assert_eq!(runs, vec![(true, 0, 2), (false, 2, 3), (true, 5, 1)]);
+    /// ```
+    pub fn runs(&self) -> Runs<'a> {
+        Runs {
+            slice: *self,
+            counter: 0,
+        }
+    }
+
+    /// Returns an iterator over the bytes that store the values of the BoolSlice.
+    ///
+    /// Values are packed most significant bit first: the value at index 0 is the highest bit
+    /// of the first byte, the value at index 7 is its lowest bit, the value at index 8 is the
+    /// highest bit of the second byte and so on.
+    ///
+    /// If BoolSlice.len() is not a multiple of 8, the unused bits of the last byte are always zero.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false, false, false, false, true, true];
+    ///
+    /// let bytes: Vec<u8> = bv.bytes().collect();
+    ///
+    /// assert_eq!(bytes, vec![0b10110000, 0b11000000]);
+    /// ```
+    pub fn bytes(&self) -> Bytes<'a> {
+        Bytes {
+            slice: *self,
+            counter: 0,
+            end: bytes_for(self.len()),
+        }
+    }
+
+    /// Copies the values of the BoolSlice into packed bytes, in the given order.
+    /// With BitOrder::Msb0, this yields the same bytes as BoolSlice::bytes().
+    ///
+    /// If BoolSlice.len() is not a multiple of 8, the unused bits of the last byte are always zero.
+    /// ```rust
+    /// use bool_vec::{boolvec, BitOrder};
+    ///
+    /// let bv = boolvec![true, false, true, true, false, false, false, false, true, true];
+    ///
+    /// assert_eq!(bv.to_bytes_with_order(BitOrder::Lsb0), vec![0b0000_1101, 0b0000_0011]);
+    /// assert_eq!(bv.to_bytes_with_order(BitOrder::Msb0), vec![0b1011_0000, 0b1100_0000]);
+    /// ```
+    pub fn to_bytes_with_order(&self, order: BitOrder) -> Vec<u8> {
+        self.bytes().map(|byte| order.convert(byte)).collect()
+    }
+
+    /// Returns the values as the bits of a u64, the first value being the most significant bit,
+    /// just like they're written in a binary literal. This is the inverse of BoolVec::from_uint().
+    ///
+    /// Returns BoolVecError::TooManyValues if the BoolSlice holds more than 64 values.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVecError};
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv.try_to_u64(), Ok(0b101));
+    /// assert_eq!(bv.slice(1..).try_to_u64(), Ok(0b01));
+    ///
+    /// assert_eq!(
+    ///     boolvec![false; 65].try_to_u64(),
+    ///     Err(BoolVecError::TooManyValues { len: 65, max: 64 })
+    /// );
+    /// ```
+    pub fn try_to_u64(&self) -> Result<u64, BoolVecError> {
+        self.try_to_uint(64).map(|value| value as u64)
+    }
+
+    /// Same as BoolSlice::try_to_u64(), returning the values as the bits of a u128
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVecError};
+    ///
+    /// assert_eq!(boolvec![true; 128].try_to_u128(), Ok(u128::MAX));
+    /// assert_eq!(
+    ///     boolvec![true; 129].try_to_u128(),
+    ///     Err(BoolVecError::TooManyValues { len: 129, max: 128 })
+    /// );
+    /// ```
+    pub fn try_to_u128(&self) -> Result<u128, BoolVecError> {
+        self.try_to_uint(128)
+    }
+
+    /// Returns the values in `range` as the bits of a u64, the first one being the most significant bit,
+    /// just like BoolSlice::try_to_u64() would on a BoolSlice viewing them.
+    /// Values are read a whole byte at a time, even when `range` doesn't start on a byte boundary.
+    ///
+    /// Just like slice indexing, this panics if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the BoolSlice.
+    /// It also panics if `range` holds more than 64 values.
+    /// ```rust
+    /// use bool_vec::BoolVec;
+    ///
+    /// // A 16 bits header: a 3 bits version, a 5 bits type and an 8 bits length
+    /// let header = BoolVec::from_u16(0b010_00111_0010_1010);
+    ///
+    /// assert_eq!(header.get_bits(..3), 0b010);
+    /// assert_eq!(header.get_bits(3..8), 0b00111);
+    /// assert_eq!(header.get_bits(8..), 42);
+    /// ```
+    pub fn get_bits<R: RangeBounds<usize>>(&self, range: R) -> u64 {
+        let (start, end) = range_to_indexes(range, self.len());
+        let len = end - start;
+
+        assert!(len <= 64, "can't fit {len} values in a u64");
+
+        self.read_u64(start, len)
+    }
+
+    /// Returns the values as the bits of an integer, failing if there are more than `max` of them
+    fn try_to_uint(&self, max: usize) -> Result<u128, BoolVecError> {
+        if self.len() > max {
+            return Err(BoolVecError::TooManyValues {
+                len: self.len(),
+                max,
+            });
+        }
+
+        let value = self
+            .bytes()
+            .fold(0, |value, byte| value << 8 | byte as u128);
+
+        // The last byte is padded with zeros past the last value
+        Ok(value >> (bytes_for(self.len()) * 8 - self.len()))
+    }
+
+    /// Returns the values as the bits of a BigUint, the first value being the most significant bit,
+    /// just like BoolSlice::try_to_u128() does for up to 128 values. Only available with the `num-bigint` feature.
+    ///
+    /// Leading false values don't change the number, so the length must be passed to BoolVec::from_biguint()
+    /// to get them back.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    /// use num_bigint::BigUint;
+    ///
+    /// let bv = boolvec![false, true, false, true];
+    ///
+    /// assert_eq!(bv.to_biguint(), BigUint::from(5u8));
+    /// assert_eq!(BoolVec::from_biguint(&bv.to_biguint(), bv.len()), Some(bv));
+    /// ```
+    #[cfg(feature = "num-bigint")]
+    pub fn to_biguint(&self) -> num_bigint::BigUint {
+        let value = num_bigint::BigUint::from_bytes_be(&self.bytes().collect::<Vec<u8>>());
+
+        // The last byte is padded with zeros past the last value
+        value >> (bytes_for(self.len()) * 8 - self.len())
+    }
+
+    /// Copies the values into an Arrow validity bitmap, packed least significant bit first,
+    /// so that the BoolSlice can be used as the null mask of an Arrow array.
+    /// Same as calling BoolSlice::to_bytes_with_order() with BitOrder::Lsb0.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// // The second and fourth values are null
+    /// let validity = boolvec![true, false, true, false, true, true, true, true, true];
+    ///
+    /// assert_eq!(validity.to_arrow_buffer(), vec![0b1111_0101, 0b0000_0001]);
+    /// ```
+    pub fn to_arrow_buffer(&self) -> Vec<u8> {
+        self.to_bytes_with_order(BitOrder::Lsb0)
+    }
+
+    /// Encodes the packed bytes storing the values as a base64 string, with the standard alphabet and padding.
+    /// Only available with the `base64` feature.
+    ///
+    /// The number of values isn't encoded, so it must be passed to BoolVec::from_base64() when decoding them.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let bv = boolvec![true, false, true, true, false, false, false, false, true];
+    ///
+    /// let encoded = bv.to_base64();
+    ///
+    /// assert_eq!(encoded, "sIA=");
+    /// assert_eq!(BoolVec::from_base64(&encoded, bv.len()).unwrap(), bv);
+    /// ```
+    #[cfg(feature = "base64")]
+    pub fn to_base64(&self) -> String {
+        use base64::Engine;
+
+        base64::engine::general_purpose::STANDARD.encode(self.bytes().collect::<Vec<u8>>())
+    }
+
+    /// Encodes the values as runs of equal consecutive values, as `(value, len)` pairs.
+    /// Runs longer than `u32::MAX` are split into several runs of the same value.
+    ///
+    /// Runs are found just like with BoolSlice::runs(), and BoolVec::from_runs() converts them back.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let mut bv = boolvec![false; 1000];
+    /// bv.slice_mut(10..20).fill(true);
+    ///
+    /// assert_eq!(bv.to_runs(), vec![(false, 10), (true, 10), (false, 980)]);
+    /// assert_eq!(BoolVec::from_runs(bv.to_runs()), bv);
+    /// ```
+    pub fn to_runs(&self) -> Vec<(bool, u32)> {
+        let mut runs = Vec::new();
+
+        for (value, _, mut len) in self.runs() {
+            while len > u32::MAX as usize {
+                runs.push((value, u32::MAX));
+                len -= u32::MAX as usize;
+            }
+
+            runs.push((value, len as u32));
+        }
+
+        runs
+    }
+
+    /// Returns the indexes of the `true` values, in increasing order.
+    /// Bytes without `true` values are skipped at once, so sparse BoolSlices are cheap to convert.
+    ///
+    /// BoolVec::from_indices() converts them back.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let mut bv = boolvec![false; 1000];
+    /// bv.set(3, true);
+    /// bv.set(700, true);
+    ///
+    /// assert_eq!(bv.to_indices(), vec![3, 700]);
+    /// assert_eq!(BoolVec::from_indices(bv.to_indices(), bv.len()), Ok(bv));
+    /// ```
+    pub fn to_indices(&self) -> Vec<usize> {
+        let mut indices = Vec::new();
+        let mut index = self.find_from(0, true);
+
+        while index < self.len() {
+            indices.push(index);
+            index = self.find_from(index + 1, true);
+        }
+
+        indices
+    }
+
+    /// Copies the indexes of the `true` values into a RoaringBitmap. Only available with the `roaring` feature.
+    ///
+    /// Each run of `true` values is inserted at once, so dense BoolSlices are cheap to convert.
+    /// Panics if a `true` value has an index that doesn't fit in a u32.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 1000];
+    /// bv.set(500, false);
+    ///
+    /// let bitmap = bv.to_roaring();
+    ///
+    /// assert_eq!(bitmap.len(), 999);
+    /// assert!(!bitmap.contains(500));
+    /// ```
+    #[cfg(feature = "roaring")]
+    pub fn to_roaring(&self) -> roaring::RoaringBitmap {
+        let mut bitmap = roaring::RoaringBitmap::new();
+
+        for (_, start, len) in self.runs().filter(|&(value, _, _)| value) {
+            let end = u32::try_from(start + len - 1).expect("index should fit in a u32");
+
+            bitmap.insert_range(start as u32..=end);
+        }
+
+        bitmap
+    }
+
+    /// Writes the values to `writer`, prefixed by a small header so that BoolVec::read_from() can read them back.
+    ///
+    /// The header holds the magic bytes `BOOL`, a format version, the bit order of the BoolSlice
+    /// and the number of values as a little endian u64. The packed bytes storing the values follow it,
+    /// in that bit order, so that BoolVec::read_from() gives back a BoolVec with the same bit order.
+    /// ```rust
+    /// use bool_vec::{boolvec, BitOrder, BoolVec};
+    ///
+    /// let bv = boolvec![true, false, true, true, false, false, false, false, true];
+    ///
+    /// let mut file = Vec::new();
+    /// bv.write_to(&mut file).unwrap();
+    ///
+    /// assert_eq!(file.len(), 14 + 2);
+    /// assert_eq!(file[..4], *b"BOOL");
+    /// assert_eq!(file[14..], [0b1011_0000, 0b1000_0000]);
+    ///
+    /// assert_eq!(BoolVec::read_from(file.as_slice()).unwrap(), bv);
+    ///
+    /// let lsb0 = BoolVec::from_bytes_with_order([0b0000_1101], 4, BitOrder::Lsb0).unwrap();
+    ///
+    /// file.clear();
+    /// lsb0.write_to(&mut file).unwrap();
+    ///
+    /// assert_eq!(file[5], BitOrder::Lsb0 as u8);
+    /// assert_eq!(file[14..], [0b0000_1101]);
+    ///
+    /// assert_eq!(BoolVec::read_from(file.as_slice()).unwrap().bit_order(), BitOrder::Lsb0);
+    /// ```
+    pub fn write_to<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(&HEADER_MAGIC)?;
+        let order = self.bit_order();
+
+        writer.write_all(&[HEADER_VERSION, order as u8])?;
+        writer.write_all(&(self.len() as u64).to_le_bytes())?;
+
+        // Bytes are written a chunk at a time, so that huge slices don't need to be copied all at once
+        let mut bytes = self.bytes().map(|byte| order.convert(byte));
+        let mut chunk = Vec::with_capacity(4096);
+
+        loop {
+            chunk.clear();
+            chunk.extend(bytes.by_ref().take(4096));
+
+            if chunk.is_empty() {
+                return Ok(());
+            }
+
+            writer.write_all(&chunk)?;
+        }
+    }
+
+    /// Returns a wrapper printing the values with Display, one character per value,
+    /// grouped and separated as requested by `options`. Padding is applied to the whole string
+    /// ```rust
+    /// use bool_vec::{boolvec, FormatOptions};
+    ///
+    /// let bv = boolvec![true, false, true, true, false, false];
+    ///
+    /// let options = FormatOptions {
+    ///     group: 3,
+    ///     separator: " | ",
+    ///     ..FormatOptions::default()
+    /// };
+    ///
+    /// assert_eq!(format!("{}", bv.display_with(options)), "101 | 100");
+    /// assert_eq!(format!("{:>4}", bv.slice(..2).display_with(options)), "  10");
+    /// ```
+    pub fn display_with(&self, options: FormatOptions<'a>) -> DisplayWith<'a> {
+        DisplayWith {
+            slice: *self,
+            options,
+        }
+    }
+
+    /// Returns an iterator over the bytes that store the values of the BoolSlice,
+    /// 8 bytes at a time packed into a u64.
+    ///
+    /// Bytes are packed big endian, so that values keep being stored most significant bit first
+    /// (check BoolSlice::bytes() documentation for more infos): the value at index 0 is the highest
+    /// bit of the first u64, and the value at index 63 is its lowest bit.
+    ///
+    /// If BoolSlice.len() is not a multiple of 64, the unused bits of the last u64 are always zero.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false; 66];
+    /// bv.set(0, true);
+    /// bv.set(65, true);
+    ///
+    /// let chunks: Vec<u64> = bv.chunks_u64().collect();
+    ///
+    /// assert_eq!(chunks, vec![1 << 63, 1 << 62]);
+    /// ```
+    pub fn chunks_u64(&self) -> ChunksU64<'a> {
+        ChunksU64 {
+            slice: *self,
+            counter: 0,
+            end: self.len().div_ceil(64),
+        }
+    }
+
+    /// Combines `self` and `other` value by value with `f`, returning the results as a new BoolVec.
+    /// Just like `Iterator::zip()`, the result is as long as the shortest of the two.
+    ///
+    /// `f` is only called once for each of the 4 possible combinations of its arguments,
+    /// then the results are applied to a whole byte at a time.
+    /// This means that `f` should always return the same result for the same arguments.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv1 = boolvec![true, true, false, false];
+    /// let bv2 = boolvec![true, false, true];
+    ///
+    /// let implication = bv1.zip_with(&bv2, |a, b| !a || b);
+    ///
+    /// assert_eq!(implication, boolvec![true, false, true]);
+    /// ```
+    pub fn zip_with<'b, F: FnMut(bool, bool) -> bool>(
+        &self,
+        other: impl Into<BoolSlice<'b>>,
+        f: F,
+    ) -> BoolVec {
+        let other = other.into();
+
+        let table = truth_table(f);
+
+        self.combined(other, move |a, b| combine_bytes(a, b, table))
+    }
+
+    /// Treating the values of `self` and `other` as big-endian binary numbers, the first value being
+    /// the most significant bit, returns their sum as a new BoolVec as long as `self`.
+    /// Carries are propagated 64 values at a time.
+    ///
+    /// Returns None if the sum doesn't fit in `self.len()` values.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv1 = boolvec![false, true, true]; // 3
+    /// let bv2 = boolvec![true, false]; // 2
+    ///
+    /// assert_eq!(bv1.checked_add(&bv2), Some(boolvec![true, false, true]));
+    /// assert_eq!(bv1.checked_add(&bv1), Some(boolvec![true, true, false]));
+    ///
+    /// assert_eq!(boolvec![true, false].checked_add(&bv2), None);
+    /// ```
+    pub fn checked_add<'b>(&self, other: impl Into<BoolSlice<'b>>) -> Option<BoolVec> {
+        let other = other.into();
+
+        let mut sum = self.to_owned();
+        let mut carry = false;
+
+        // Chunks of 64 values are added starting from the least significant ones,
+        // `low` being the number of values coming after the chunk
+        for low in (0..self.len().max(other.len())).step_by(64) {
+            let range = |len: usize| len.saturating_sub(low + 64)..len.saturating_sub(low);
+            let width = self.len().saturating_sub(low).min(64);
+
+            let (chunk, carry_a) = self
+                .get_bits(range(self.len()))
+                .overflowing_add(carry as u64);
+            let (chunk, carry_b) = chunk.overflowing_add(other.get_bits(range(other.len())));
+
+            carry = carry_a || carry_b || (width < 64 && chunk >> width != 0);
+
+            sum.set_bits(range(self.len()), chunk);
+        }
+
+        (!carry).then_some(sum)
+    }
+
+    /// Treating the values as a big-endian binary number, the first value being the most significant bit,
+    /// returns its reflected Gray code as a new BoolVec of the same length.
+    /// Each value of the result is the XOR of the value at the same position and the one before it.
+    ///
+    /// BoolVec::from_gray() converts the result back.
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVec};
+    ///
+    /// let bv = BoolVec::from_u8(0b0110_1101);
+    ///
+    /// assert_eq!(bv.to_gray(), BoolVec::from_u8(0b0101_1011));
+    /// assert_eq!(BoolVec::from_gray(&bv.to_gray()), bv);
+    /// ```
+    pub fn to_gray(&self) -> BoolVec {
+        let mut gray = self.to_owned();
+
+        gray.shift_right(1, false);
+        gray.zip_with_in_place(self, |shifted, value| shifted != value);
+
+        gray
+    }
+
+    /// Gathers the values of `self` at the indexes where `mask` is `true` into a new BoolVec, keeping their order,
+    /// just like the BMI2 `pext` instruction does with the bits of an integer.
+    /// Values past the end of the shortest one are ignored.
+    ///
+    /// Values are gathered a whole byte at a time, skipping the bytes of `mask` not selecting any value.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, false, true, true, false];
+    /// let mask = boolvec![true, true, false, true, false, true];
+    ///
+    /// assert_eq!(bv.compress(&mask), boolvec![true, false, true, false]);
+    ///
+    /// // Compressing and expanding with the same mask clears the values it doesn't select
+    /// assert_eq!(bv.compress(&mask).expand(&mask), &bv & &mask);
+    /// ```
+    pub fn compress<'b>(&self, mask: impl Into<BoolSlice<'b>>) -> BoolVec {
+        let mask = mask.into();
+
+        let len = self.len().min(mask.len());
+        let compressed_len = mask.slice(..len).count_ones();
+
+        let mut result = BoolVec::with_capacity(compressed_len);
+        result.resize(compressed_len, false);
+
+        let mut written = 0;
+
+        for start in (0..len).step_by(8) {
+            let selected = mask.read_byte(start) & (u8::MAX << (8 - (len - start).min(8)));
+
+            if selected == 0 {
+                continue;
+            }
+
+            let count = selected.count_ones() as usize;
+
+            result.as_mut_slice().write_byte(
+                written,
+                gather_bits(self.read_byte(start), selected),
+                count,
+            );
+            written += count;
+        }
+
+        result
+    }
+
+    /// Scatters the values of `self` to the indexes where `mask` is `true`, keeping their order,
+    /// into a new BoolVec as long as `mask` whose other values are `false`.
+    /// This is the inverse of BoolSlice::compress(), just like the BMI2 `pdep` instruction is the inverse of `pext`.
+    ///
+    /// Once every value of `self` has been scattered, the remaining indexes selected by `mask` are left `false`.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    /// let mask = boolvec![false, true, true, false, true, true];
+    ///
+    /// assert_eq!(bv.expand(&mask), boolvec![false, true, false, false, true, false]);
+    /// ```
+    pub fn expand<'b>(&self, mask: impl Into<BoolSlice<'b>>) -> BoolVec {
+        let mask = mask.into();
+
+        let len = mask.len();
+
+        let mut result = BoolVec::with_capacity(len);
+        result.resize(len, false);
+
+        let mut read = 0;
+
+        for start in (0..len).step_by(8) {
+            let selected = mask.read_byte(start);
+
+            if selected == 0 {
+                continue;
+            }
+
+            // Values past the end of `self` are read as `false`
+            let byte = scatter_bits(self.read_byte(read), selected);
+
+            result
+                .as_mut_slice()
+                .write_byte(start, byte, (len - start).min(8));
+            read += selected.count_ones() as usize;
+        }
+
+        result
+    }
+
+    /// Returns the values of `self` at the indexes where `mask` is `true`, such as the rows matching a predicate,
+    /// as a new BoolVec. This is the same as BoolSlice::compress(), making a pair with BoolSliceMut::masked_set()
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let column = boolvec![true, false, false, true, true];
+    /// let matching_rows = boolvec![false, true, true, true, false];
+    ///
+    /// assert_eq!(column.masked_get(&matching_rows), boolvec![false, false, true]);
+    /// ```
+    pub fn masked_get<'b>(&self, mask: impl Into<BoolSlice<'b>>) -> BoolVec {
+        let mask = mask.into();
+
+        self.compress(mask)
+    }
+
+    /// Seeing each BoolSlice as the set of the indexes of its `true` values, returns true if every `true` value
+    /// of `self` is also `true` in `other`. Values past the end of the shortest one are treated as `false`.
+    ///
+    /// Values are compared a whole byte at a time, stopping at the first one that isn't in `other`.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, false];
+    ///
+    /// assert!(bv.is_subset(&boolvec![true, true, true]));
+    /// assert!(!bv.is_subset(&boolvec![true, true, false, true]));
+    /// ```
+    pub fn is_subset<'b>(&self, other: impl Into<BoolSlice<'b>>) -> bool {
+        let other = other.into();
+
+        (0..self.len())
+            .step_by(8)
+            .all(|start| self.read_byte(start) & !other.read_byte(start) == 0)
+    }
+
+    /// Seeing each BoolSlice as the set of the indexes of its `true` values, returns true if every `true` value
+    /// of `other` is also `true` in `self`. Same as calling BoolSlice::is_subset() on `other`.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, true, true, false];
+    ///
+    /// assert!(bv.is_superset(&boolvec![false, true, true]));
+    /// assert!(!bv.is_superset(&boolvec![false, false, false, true]));
+    /// ```
+    pub fn is_superset<'b>(&self, other: impl Into<BoolSlice<'b>>) -> bool {
+        let other = other.into();
+
+        other.is_subset(self)
+    }
+
+    /// Seeing each BoolSlice as the set of the indexes of its `true` values, returns true if no value
+    /// is `true` in both of them.
+    ///
+    /// Values are compared a whole byte at a time, stopping at the first one that is `true` in both.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, false];
+    ///
+    /// assert!(bv.is_disjoint(&boolvec![false, true, false, true, true]));
+    /// assert!(!bv.is_disjoint(&boolvec![false, false, true]));
+    /// ```
+    pub fn is_disjoint<'b>(&self, other: impl Into<BoolSlice<'b>>) -> bool {
+        let other = other.into();
+
+        (0..self.len().min(other.len()))
+            .step_by(8)
+            .all(|start| self.read_byte(start) & other.read_byte(start) == 0)
+    }
+
+    /// Returns the number of values that are `true` in both `self` and `other`,
+    /// which is the number of `true` values of `self & other` without allocating it.
+    ///
+    /// Values are combined and counted a whole byte at a time, in a single pass.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv1 = boolvec![true, true, false, true];
+    /// let bv2 = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv1.and_count(&bv2), 1);
+    /// ```
+    pub fn and_count<'b>(&self, other: impl Into<BoolSlice<'b>>) -> usize {
+        let other = other.into();
+
+        self.combined_count(other, self.len().min(other.len()), |a, b| a & b)
+    }
+
+    /// Returns the number of values that are `true` in either `self` or `other`,
+    /// values past the end of the shortest one being treated as `false`.
+    ///
+    /// Values are combined and counted a whole byte at a time, in a single pass.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv1 = boolvec![true, true, false, true];
+    /// let bv2 = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv1.or_count(&bv2), 4);
+    /// ```
+    pub fn or_count<'b>(&self, other: impl Into<BoolSlice<'b>>) -> usize {
+        let other = other.into();
+
+        self.combined_count(other, self.len().max(other.len()), |a, b| a | b)
+    }
+
+    /// Returns the number of values that are `true` in exactly one of `self` and `other`,
+    /// values past the end of the shortest one being treated as `false`.
+    /// This is the Hamming distance between the two when they have the same length.
+    ///
+    /// Values are combined and counted a whole byte at a time, in a single pass.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv1 = boolvec![true, true, false, true];
+    /// let bv2 = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv1.xor_count(&bv2), 3);
+    /// ```
+    pub fn xor_count<'b>(&self, other: impl Into<BoolSlice<'b>>) -> usize {
+        let other = other.into();
+
+        self.combined_count(other, self.len().max(other.len()), |a, b| a ^ b)
+    }
+
+    /// Seeing each BoolSlice as the set of the indexes of its `true` values, returns their Jaccard similarity:
+    /// the number of values `true` in both divided by the number of values `true` in either.
+    /// Returns 1.0 if neither has any `true` value, since they hold the same empty set.
+    ///
+    /// Both counts are computed a whole byte at a time without allocating, just like BoolSlice::and_count()
+    /// and BoolSlice::or_count() do.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv1 = boolvec![true, true, false, true];
+    /// let bv2 = boolvec![true, false, true, true];
+    ///
+    /// assert_eq!(bv1.jaccard_similarity(&bv2), 0.5);
+    /// assert_eq!(boolvec![false; 3].jaccard_similarity(&boolvec![]), 1.0);
+    /// ```
+    pub fn jaccard_similarity<'b>(&self, other: impl Into<BoolSlice<'b>>) -> f64 {
+        let other = other.into();
+
+        match self.or_count(other) {
+            0 => 1.0,
+            union => self.and_count(other) as f64 / union as f64,
+        }
+    }
+
+    /// Seeing each BoolSlice as the set of the indexes of its `true` values, returns their Sørensen–Dice coefficient:
+    /// twice the number of values `true` in both divided by the sum of the number of `true` values of each.
+    /// Returns 1.0 if neither has any `true` value, since they hold the same empty set.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv1 = boolvec![true, true, false, true];
+    /// let bv2 = boolvec![true, false, true, true];
+    ///
+    /// assert_eq!(bv1.dice_coefficient(&bv2), 2.0 / 3.0);
+    /// ```
+    pub fn dice_coefficient<'b>(&self, other: impl Into<BoolSlice<'b>>) -> f64 {
+        let other = other.into();
+
+        match self.count_ones() + other.count_ones() {
+            0 => 1.0,
+            total => 2.0 * self.and_count(other) as f64 / total as f64,
+        }
+    }
+
+    /// Seeing each BoolSlice as a vector of zeros and ones, returns their cosine similarity:
+    /// the number of values `true` in both divided by the geometric mean of the number of `true` values of each.
+    /// Returns 1.0 if neither has any `true` value, and 0.0 if only one of them has none.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv1 = boolvec![true, true, false, false];
+    /// let bv2 = boolvec![true, false, false, false];
+    ///
+    /// assert_eq!(bv1.cosine_similarity(&bv2), 1.0 / 2f64.sqrt());
+    /// assert_eq!(bv1.cosine_similarity(&boolvec![false; 4]), 0.0);
+    /// ```
+    pub fn cosine_similarity<'b>(&self, other: impl Into<BoolSlice<'b>>) -> f64 {
+        let other = other.into();
+
+        match (self.count_ones(), other.count_ones()) {
+            (0, 0) => 1.0,
+            (0, _) | (_, 0) => 0.0,
+            (ones, other_ones) => {
+                self.and_count(other) as f64 / ((ones as f64) * (other_ones as f64)).sqrt()
+            }
+        }
+    }
+
+    /// Returns an iterator over the indexes where `self` and `other` hold different values, in increasing order.
+    /// Values past the end of the shortest one are treated as `false`.
+    ///
+    /// Values are compared a whole byte at a time, skipping the bytes where they're all equal.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv1 = boolvec![true, false, true, true, false];
+    /// let bv2 = boolvec![true, true, true, false, false, false, true];
+    ///
+    /// assert_eq!(bv1.differences(&bv2).collect::<Vec<_>>(), vec![1, 3, 6]);
+    /// assert_eq!(bv1.differences(&bv2).count(), bv1.xor_count(&bv2));
+    /// ```
+    pub fn differences(&self, other: impl Into<BoolSlice<'a>>) -> Differences<'a> {
+        let other = other.into();
+
+        Differences {
+            slice: *self,
+            other,
+            counter: 0,
+            end: self.len().max(other.len()),
+            base: 0,
+            pending: 0,
+        }
+    }
+
+    /// Returns true if `needle` is a prefix of the BoolSlice.
+    /// `needle` can be a BoolVec, a BoolSlice or a slice, array or Vec of bools.
+    ///
+    /// When `needle` is a BoolVec or a BoolSlice, values are compared a whole byte at a time.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false];
+    ///
+    /// assert!(bv.starts_with(&boolvec![true, false]));
+    /// assert!(bv.starts_with(&bv.slice(..4)));
+    /// assert!(bv.starts_with(&[true, false, true]));
+    /// assert!(bv.starts_with(&vec![]));
+    ///
+    /// assert!(!bv.starts_with(&[false]));
+    /// assert!(!bv.starts_with(&boolvec![true; 6]));
+    /// ```
+    pub fn starts_with<S: BoolSequence + ?Sized>(&self, needle: &S) -> bool {
+        let len = needle.len();
+
+        len <= self.len() && needle.matches(self.slice(..len))
+    }
+
+    /// Returns true if `needle` is a suffix of the BoolSlice.
+    /// `needle` can be a BoolVec, a BoolSlice or a slice, array or Vec of bools.
+    ///
+    /// When `needle` is a BoolVec or a BoolSlice, values are compared a whole byte at a time.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false];
+    ///
+    /// assert!(bv.ends_with(&boolvec![true, false]));
+    /// assert!(bv.ends_with(&bv.slice(1..)));
+    /// assert!(bv.ends_with(&[true, true, false]));
+    ///
+    /// assert!(!bv.ends_with(&[true]));
+    /// ```
+    pub fn ends_with<S: BoolSequence + ?Sized>(&self, needle: &S) -> bool {
+        let len = needle.len();
+
+        len <= self.len() && needle.matches(self.slice(self.len() - len..))
+    }
+
+    /// Copies the values of the BoolSlice into a Vec<bool>
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv.slice(1..).to_vec(), vec![false, true]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<bool> {
+        self.iter().collect()
+    }
+
+    /// Copies the values of the BoolSlice into a new BoolVec with the same bit order, a whole byte at a time
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv.slice(1..).to_owned(), boolvec![false, true]);
+    /// ```
+    pub fn to_owned(&self) -> BoolVec {
+        let order = self.bit_order();
+        let mut owned = BoolVec::with_capacity(self.len());
+        owned.order = order;
+
+        for (byte_index, byte) in owned.raw_bytes_mut()[..bytes_for(self.len())]
+            .iter_mut()
+            .enumerate()
+        {
+            *byte = order.convert(self.read_byte(byte_index * 8));
+        }
+
+        // Bits past the end are read as zero, so they don't need to be cleared
+        owned.length = self.len();
+
+        owned
+    }
+}
+
+impl<'a> BoolSliceMut<'a> {
+    /// Builds a BoolSliceMut viewing `len` values stored in `order`, starting from the bit `head` of the byte at `ptr`.
+    ///
+    /// # Safety
+    /// Same as BoolSlice::from_raw_parts(), with `ptr` coming from a mutable borrow.
+    /// The values viewed must not be accessed through anything else for `'a`
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: NonNull<u8>,
+        head: usize,
+        len: usize,
+        order: BitOrder,
+    ) -> Self {
+        Self {
+            slice: BoolSlice {
+                shared_edges: true,
+                // SAFETY: the requirements are forwarded to the caller
+                ..unsafe { BoolSlice::from_raw_parts(ptr, head, len, order) }
+            },
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a BoolSlice viewing the same values, which can't be changed while it is in use
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolSlice};
+    ///
+    /// let mut bv = boolvec![true, false, true, true];
+    ///
+    /// let mut slice = bv.slice_mut(1..);
+    /// slice.set(0, true);
+    ///
+    /// let view: BoolSlice<'_> = slice.as_slice();
+    ///
+    /// assert_eq!(view, boolvec![true; 3]);
+    /// ```
+    pub fn as_slice(&self) -> BoolSlice<'_> {
+        self.slice
+    }
+
+    /// Returns a BoolSliceMut viewing the same values, while this one is borrowed mutably
+    fn reborrow(&mut self) -> BoolSliceMut<'_> {
+        BoolSliceMut {
+            slice: self.slice,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a BoolSliceMut viewing the values from `start` (included) to `end` (excluded),
+    /// which must not be greater than BoolSliceMut.len(). Its pointer is derived from the one of this slice,
+    /// so that it can write the same bytes
+    pub(crate) fn into_subslice(self, start: usize, end: usize) -> BoolSliceMut<'a> {
+        BoolSliceMut {
+            slice: self.slice.subslice(start, end),
+            marker: PhantomData,
+        }
+    }
+
+    /// Same as BoolSliceMut::split_at_mut(), consuming this slice so that the halves live as long as it would
+    pub(crate) fn into_split_at(self, mid: usize) -> (BoolSliceMut<'a>, BoolSliceMut<'a>) {
+        assert!(mid <= self.len(), "mid > len");
+
+        let len = self.len();
+
+        // The halves view disjoint values of this slice, which is consumed.
+        // The byte they may share is only ever accessed atomically
+        (
+            BoolSliceMut {
+                slice: self.slice.subslice(0, mid),
+                marker: PhantomData,
+            },
+            BoolSliceMut {
+                slice: self.slice.subslice(mid, len),
+                marker: PhantomData,
+            },
+        )
+    }
+
+    /// Mutable version of BoolSlice::interior_bytes()
+    fn interior_bytes_mut(&mut self) -> &mut [u8] {
+        let len = self.slice.byte_len().saturating_sub(2);
+
+        // SAFETY: interior bytes are never shared with other slices, and this one is borrowed mutably
+        unsafe { std::slice::from_raw_parts_mut(self.slice.byte_ptr().wrapping_add(1), len) }
+    }
+
+    /// Sets the bits selected by `mask` in the byte at `byte_index` to the ones of `bits`,
+    /// leaving the other bits untouched. Both are given most significant bit first, whatever the bit order of the slice
+    fn write_masked(&mut self, byte_index: usize, mask: u8, bits: u8) {
+        assert!(byte_index < self.slice.byte_len());
+
+        // SAFETY: the byte is inside the slice
+        unsafe { self.write_masked_unchecked(byte_index, mask, bits) }
+    }
+
+    /// Same as BoolSliceMut::write_masked(), without checking that `byte_index` is inside the slice.
+    ///
+    /// # Safety
+    /// `byte_index` must be lower than BoolSlice::byte_len()
+    unsafe fn write_masked_unchecked(&mut self, byte_index: usize, mask: u8, bits: u8) {
+        let is_edge_byte = self.slice.is_edge_byte(byte_index);
+
+        let order = self.slice.bit_order();
+        let (mask, bits) = (order.convert(mask), order.convert(bits));
+
+        // SAFETY: the byte is inside the slice, which is borrowed mutably.
+        // Edge bytes are updated atomically, since other slices may be updating their other bits
+        unsafe {
+            let ptr = self.slice.byte_ptr().add(byte_index);
+
+            if is_edge_byte {
+                let byte = AtomicU8::from_ptr(ptr);
+
+                // Only the selected bits are touched, so concurrent updates to the others are kept
+                if mask & !bits != 0 {
+                    byte.fetch_and(!(mask & !bits), Ordering::Relaxed);
+                }
+                if mask & bits != 0 {
+                    byte.fetch_or(mask & bits, Ordering::Relaxed);
+                }
+            } else {
+                *ptr = (*ptr & !mask) | (bits & mask);
+            }
+        }
+    }
+
+    /// Writes the highest `count` bits of `byte` as the values starting at `start`,
+    /// even when `start` is not a multiple of 8. `count` must be between 1 and 8
+    pub(crate) fn write_byte(&mut self, start: usize, byte: u8, count: usize) {
+        let index = BoolIndex::from(self.slice.head() + start);
+        let mask = u8::MAX << (8 - count);
+
+        self.write_masked(
+            index.byte_index,
+            mask >> index.bit_index,
+            byte >> index.bit_index,
+        );
+
+        if index.bit_index != 0 {
+            let low_mask = mask << (8 - index.bit_index);
+
+            if low_mask != 0 {
+                self.write_masked(
+                    index.byte_index + 1,
+                    low_mask,
+                    byte << (8 - index.bit_index),
+                );
+            }
+        }
+    }
+
+    /// Combines the values of `self` and `other` with `op` a whole byte at a time,
+    /// storing the results in `self`. Values of `self` past the end of `other` are left untouched
+    fn combine_in_place<F: Fn(u8, u8) -> u8>(&mut self, other: BoolSlice<'_>, op: F) {
+        let len = self.len().min(other.len());
+
+        for start in (0..len).step_by(8) {
+            let byte = op(self.slice.read_byte(start), other.read_byte(start));
+
+            self.write_byte(start, byte, (len - start).min(8));
+        }
+    }
+
+    /// Same as BoolSliceMut::set(), but returns a BoolVecError explaining why the call failed
+    /// if index overflows BoolSliceMut.len()
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVecError};
+    ///
+    /// fn enable(bv: &mut bool_vec::BoolVec, flags: &[usize]) -> Result<(), BoolVecError> {
+    ///     for &flag in flags {
+    ///         bv.try_set(flag, true)?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// let mut bv = boolvec![false; 4];
+    ///
+    /// assert!(enable(&mut bv, &[0, 2]).is_ok());
+    /// assert_eq!(bv, boolvec![true, false, true, false]);
+    ///
+    /// let error = enable(&mut bv, &[1, 7]).unwrap_err();
+    /// assert_eq!(error.to_string(), "index 7 is out of bounds for a length of 4");
+    /// ```
+    pub fn try_set(&mut self, int_index: usize, value: bool) -> Result<(), BoolVecError> {
+        self.slice.check_index(int_index)?;
+
+        // SAFETY: the index was just checked
+        unsafe { self.set_unchecked(int_index, value) };
+
+        Ok(())
+    }
+
+    /// Same as BoolSliceMut::negate(), but returns a BoolVecError explaining why the call failed
+    /// if index overflows BoolSliceMut.len()
+    /// ```rust
+    /// use bool_vec::{boolvec, BoolVecError};
+    ///
+    /// let mut bv = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv.try_negate(0), Ok(false));
+    /// assert_eq!(bv.try_negate(5), Err(BoolVecError::IndexOutOfBounds { index: 5, len: 3 }));
+    /// ```
+    pub fn try_negate(&mut self, int_index: usize) -> Result<bool, BoolVecError> {
+        let negated_value = !self.try_get(int_index)?;
+
+        // SAFETY: the index was just checked by self.try_get()
+        unsafe { self.set_unchecked(int_index, negated_value) };
+
+        Ok(negated_value)
+    }
+
+    /// Set bool value in a BoolSliceMut, without checking that `int_index` is lower than BoolSliceMut.len()
+    ///
+    /// # Safety
+    /// Calling this method with an index overflowing BoolSliceMut.len() is undefined behavior.
+    /// Use BoolSliceMut::set() when in doubt
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false; 3];
+    ///
+    /// // SAFETY: 2 is lower than bv.len()
+    /// unsafe { bv.set_unchecked(2, true) };
+    ///
+    /// assert_eq!(bv, boolvec![false, false, true]);
+    /// ```
+    pub unsafe fn set_unchecked(&mut self, int_index: usize, value: bool) {
+        let index = BoolIndex::from(self.slice.head() + int_index);
+        let bits = if value { u8::MAX } else { 0 };
+
+        // SAFETY: the caller guarantees that the value is inside the slice
+        unsafe { self.write_masked_unchecked(index.byte_index, 1 << (7 - index.bit_index), bits) };
+    }
+
+    /// Returns a BitMut proxy giving mutable access to the value at `int_index`,
+    /// or None if index overflows BoolSliceMut.len().
+    ///
+    /// The proxy works like a `&mut bool`, and writes the value back when it is dropped
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true];
+    ///
+    /// bv.get_mut(0).unwrap().set(false);
+    /// *bv.get_mut(1).unwrap() = true;
+    ///
+    /// assert_eq!(bv, boolvec![false, true, true]);
+    /// assert!(bv.get_mut(3).is_none());
+    /// ```
+    pub fn get_mut(&mut self, int_index: usize) -> Option<BitMut<'_>> {
+        let value = self.get(int_index)?;
+
+        Some(BitMut::new(self.reborrow(), int_index, value))
+    }
+
+    /// Sets all the specified `(index, value)` pairs. If an index appears more than once,
+    /// the last value wins.
+    /// Returns None if any index overflows BoolSliceMut.len(), leaving the BoolSliceMut untouched.
+    ///
+    /// Updates are grouped by the byte they land in, so that every byte is only written once
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false; 10];
+    ///
+    /// assert_eq!(bv.set_many(&[(9, true), (1, true), (2, true), (2, false)]), Some(()));
+    /// assert_eq!(bv, boolvec![false, true, false, false, false, false, false, false, false, true]);
+    ///
+    /// assert_eq!(bv.set_many(&[(0, true), (10, true)]), None);
+    /// assert_eq!(bv.get(0), Some(false));
+    /// ```
+    pub fn set_many(&mut self, updates: &[(usize, bool)]) -> Option<()> {
+        if updates
+            .iter()
+            .any(|&(int_index, _)| int_index >= self.len())
+        {
+            return None;
+        }
+
+        let head = self.slice.head();
+
+        // Sorting is stable, so updates to the same value keep their order
+        let mut sorted = updates.to_vec();
+        sorted.sort_by_key(|&(int_index, _)| (head + int_index) / 8);
+
+        for group in sorted.chunk_by(|a, b| (head + a.0) / 8 == (head + b.0) / 8) {
+            let byte_index = (head + group[0].0) / 8;
+
+            let mut mask = 0;
+            let mut bits = 0;
+
+            for &(int_index, value) in group {
+                let bit = 1 << (7 - (head + int_index) % 8);
+
+                mask |= bit;
+                bits = if value { bits | bit } else { bits & !bit };
+            }
+
+            self.write_masked(byte_index, mask, bits);
+        }
+
+        Some(())
+    }
+
+    /// Returns a BoolSliceMut viewing the specified range of values of this BoolSliceMut.
+    ///
+    /// Just like slice indexing, this panics if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the BoolSliceMut.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false; 6];
+    ///
+    /// let mut slice = bv.slice_mut(1..);
+    ///
+    /// slice.slice_mut(1..3).fill(true);
+    ///
+    /// assert_eq!(bv, boolvec![false, false, true, true, false, false]);
+    /// ```
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> BoolSliceMut<'_> {
+        let (start, end) = range_to_indexes(range, self.len());
+
+        self.reborrow().into_subslice(start, end)
+    }
+
+    /// Divides the BoolSliceMut into two mutable halves at `mid`: the first one holds the values
+    /// from 0 to `mid` (excluded) and the second one the values from `mid` to BoolSliceMut.len().
+    ///
+    /// The halves can be changed independently, even from different threads:
+    /// when `mid` is not a multiple of 8 the byte holding values of both halves is updated atomically.
+    ///
+    /// Just like with slices, this panics if `mid` is greater than BoolSliceMut.len()
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false; 20];
+    ///
+    /// let (mut left, mut right) = bv.split_at_mut(5);
+    ///
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| left.fill(true));
+    ///     s.spawn(|| right.set(0, true));
+    /// });
+    ///
+    /// assert_eq!(bv.count_ones(), 6);
+    /// assert_eq!(bv.get(5), Some(true));
+    /// assert_eq!(bv.get(6), Some(false));
+    /// ```
+    ///
+    /// Both halves can keep reading and writing the values next to `mid` at the same time,
+    /// without losing the changes made by the other one:
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false; 24];
+    ///
+    /// // The values from 8 to 16 are held by the same byte, split between the two halves at 10
+    /// let mut middle = bv.slice_mut(3..21);
+    /// let (mut left, mut right) = middle.split_at_mut(7);
+    ///
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| {
+    ///         for i in 0..left.len() {
+    ///             left.set(i, true);
+    ///         }
+    ///
+    ///         assert_eq!(left.count_ones(), 7);
+    ///     });
+    ///     s.spawn(|| {
+    ///         for i in (0..right.len()).step_by(2) {
+    ///             right.negate(i);
+    ///         }
+    ///
+    ///         assert_eq!(right.count_ones(), 6);
+    ///     });
+    /// });
+    ///
+    /// assert_eq!(bv.count_ones(), 13);
+    /// assert_eq!(bv.slice(8..16), [true, true, true, false, true, false, true, false]);
+    /// assert_eq!(bv.slice(..3), [false; 3]);
+    /// assert_eq!(bv.slice(21..), [false; 3]);
+    /// ```
+    pub fn split_at_mut(&mut self, mid: usize) -> (BoolSliceMut<'_>, BoolSliceMut<'_>) {
+        self.reborrow().into_split_at(mid)
+    }
+
+    /// Set bool value in the BoolSliceMut. Returns None if index overflows BoolSliceMut.len()
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true];
+    ///
+    /// let mut slice = bv.slice_mut(1..);
+    /// slice.set(1, false);
+    ///
+    /// assert_eq!(slice.set(2, true), None);
+    /// assert_eq!(bv, boolvec![true, false, false]);
+    /// ```
+    pub fn set(&mut self, int_index: usize, value: bool) -> Option<()> {
+        if int_index >= self.len() {
+            return None;
+        }
+
+        let index = BoolIndex::from(self.slice.head() + int_index);
+
+        let bits = if value { u8::MAX } else { 0 };
+
+        self.write_masked(index.byte_index, 1 << (7 - index.bit_index), bits);
+
+        Some(())
+    }
+
+    /// Alters BoolSliceMut by negating the value at the specified index.
+    /// Returns None if index overflows BoolSliceMut.len(), otherwise returns Some(negated value)
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true];
+    ///
+    /// assert_eq!(bv.slice_mut(1..).negate(0), Some(true));
+    /// assert_eq!(bv, boolvec![true, true, true]);
+    /// ```
+    pub fn negate(&mut self, int_index: usize) -> Option<bool> {
+        // Index overflow check is done by self.get()
+        let negated_value = !self.get(int_index)?;
+        let _ = self.set(int_index, negated_value);
+
+        Some(negated_value)
+    }
+
+    /// Sets every value of the BoolSliceMut to `value`, leaving the values around it untouched.
+    /// Whole bytes are filled at once.
+    ///
+    /// With the `rayon` feature, huge ranges of values are filled in parallel.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false; 20];
+    ///
+    /// bv.slice_mut(3..17).fill(true);
+    ///
+    /// assert_eq!(bv.count_ones(), 14);
+    /// assert_eq!(bv.get(2), Some(false));
+    /// assert_eq!(bv.get(3), Some(true));
+    /// assert_eq!(bv.get(16), Some(true));
+    /// assert_eq!(bv.get(17), Some(false));
+    /// ```
+    pub fn fill(&mut self, value: bool) {
+        if self.is_empty() {
+            return;
+        }
+
+        let head = self.slice.head();
+        let last = BoolIndex::from(head + self.len() - 1);
+
+        // Masks selecting the bits that belong to the slice inside the first and the last byte
+        let first_mask = u8::MAX >> head;
+        let last_mask = u8::MAX << (7 - last.bit_index);
+
+        let bits = if value { u8::MAX } else { 0 };
+
+        if last.byte_index == 0 {
+            self.write_masked(0, first_mask & last_mask, bits);
+            return;
+        }
+
+        self.write_masked(0, first_mask, bits);
+        fill_bytes(self.interior_bytes_mut(), bits);
+        self.write_masked(last.byte_index, last_mask, bits);
+    }
+
+    /// Swaps the values at positions `a` and `b`.
+    /// Returns None if either index overflows BoolSliceMut.len(), leaving the BoolSliceMut untouched
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, false];
+    ///
+    /// assert_eq!(bv.swap(0, 2), Some(()));
+    /// assert_eq!(bv, boolvec![false, false, true]);
+    ///
+    /// assert_eq!(bv.swap(0, 3), None);
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) -> Option<()> {
+        let value_a = self.get(a)?;
+        let value_b = self.get(b)?;
+
+        if value_a != value_b {
+            let _ = self.set(a, value_b);
+            let _ = self.set(b, value_a);
+        }
+
+        Some(())
+    }
+
+    /// Stores the lowest bits of `value` as the values in `range`, the most significant one first.
+    /// This is the inverse of BoolSlice::get_bits(), and bits of `value` that don't fit in `range` are ignored.
+    /// Values are written a whole byte at a time, even when `range` doesn't start on a byte boundary.
+    ///
+    /// Just like slice indexing, this panics if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the BoolSliceMut.
+    /// It also panics if `range` holds more than 64 values.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut header = boolvec![false; 16];
+    ///
+    /// header.set_bits(..3, 0b010);
+    /// header.set_bits(3..8, 0b00111);
+    /// header.set_bits(8.., 42);
+    ///
+    /// assert_eq!(header.try_to_u64(), Ok(0b010_00111_0010_1010));
+    /// ```
+    pub fn set_bits<R: RangeBounds<usize>>(&mut self, range: R, value: u64) {
+        let (start, end) = range_to_indexes(range, self.len());
+        let len = end - start;
+
+        assert!(len <= 64, "can't fit {len} values in a u64");
+
+        // The values to store are moved to the highest bits, so that each byte starts with the next value
+        let bytes = value
+            .checked_shl(64 - len as u32)
+            .unwrap_or(0)
+            .to_be_bytes();
+
+        for offset in (0..len).step_by(8) {
+            self.write_byte(start + offset, bytes[offset / 8], (len - offset).min(8));
+        }
+    }
+
+    /// Combines `self` and `other` value by value with `f`, storing the results in `self`.
+    /// If `other` is shorter than `self`, the values of `self` past the end of `other` are left untouched.
+    ///
+    /// Just like with BoolSlice::zip_with(), `f` is only called once for each of the 4 possible
+    /// combinations of its arguments, then the results are applied to a whole byte at a time.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv1 = boolvec![true, true, false, false, true];
+    /// let bv2 = boolvec![true, false, true, false];
+    ///
+    /// bv1.zip_with_in_place(&bv2, |a, b| a != b);
+    ///
+    /// assert_eq!(bv1, boolvec![false, true, true, false, true]);
+    /// ```
+    pub fn zip_with_in_place<'b, F: FnMut(bool, bool) -> bool>(
+        &mut self,
+        other: impl Into<BoolSlice<'b>>,
+        f: F,
+    ) {
+        let other = other.into();
+
+        let table = truth_table(f);
+
+        self.combine_in_place(other, move |a, b| combine_bytes(a, b, table));
+    }
+
+    /// Copies the values in the `src` range to the position starting at `dest`, inside the same BoolSliceMut.
+    /// The two ranges can overlap. Values are moved a whole byte at a time, shifting them
+    /// into place when the ranges don't start at the same position inside a byte.
+    ///
+    /// Just like `slice::copy_within()`, this panics if either range is out of bounds.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, true, false, true, false, false];
+    ///
+    /// bv.copy_within(0..4, 2);
+    ///
+    /// assert_eq!(bv, boolvec![true, true, true, true, false, true]);
+    ///
+    /// bv.copy_within(3.., 0);
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, true, false, true]);
+    /// ```
+    pub fn copy_within<R: RangeBounds<usize>>(&mut self, src: R, dest: usize) {
+        let (start, end) = range_to_indexes(src, self.len());
+        let count = end - start;
+
+        assert!(dest <= self.len() - count, "dest is out of bounds");
+
+        if dest <= start {
+            // Every value is read before the destination reaches it
+            for offset in (0..count).step_by(8) {
+                let byte = self.slice.read_byte(start + offset);
+
+                self.write_byte(dest + offset, byte, (count - offset).min(8));
+            }
+        } else {
+            // The destination comes after the source, so values are copied starting from the end
+            let mut copied_end = count;
+
+            while copied_end > 0 {
+                let chunk = copied_end.min(8);
+                let offset = copied_end - chunk;
+                let byte = self.slice.read_byte(start + offset);
+
+                self.write_byte(dest + offset, byte, chunk);
+                copied_end = offset;
+            }
+        }
+    }
+
+    /// Moves every value `n` positions towards the start of the BoolSliceMut, just like `<<` does with the bits of an integer.
+    /// The first `n` values are discarded, and the last `n` ones are set to `fill`.
+    ///
+    /// Values are moved a whole byte at a time, just like with BoolSliceMut::copy_within().
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true, true];
+    ///
+    /// bv.shift_left(1, true);
+    ///
+    /// assert_eq!(bv, boolvec![false, true, true, true]);
+    ///
+    /// bv.shift_left(10, false);
+    ///
+    /// assert_eq!(bv, boolvec![false; 4]);
+    /// ```
+    pub fn shift_left(&mut self, n: usize, fill: bool) {
+        let n = n.min(self.len());
+        let len = self.len();
+
+        self.copy_within(n.., 0);
+        self.slice_mut(len - n..).fill(fill);
+    }
+
+    /// Moves every value `n` positions towards the end of the BoolSliceMut, just like `>>` does with the bits of an integer.
+    /// The last `n` values are discarded, and the first `n` ones are set to `fill`.
+    ///
+    /// Values are moved a whole byte at a time, just like with BoolSliceMut::copy_within().
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, false, true, true];
+    ///
+    /// bv.shift_right(1, true);
+    ///
+    /// assert_eq!(bv, boolvec![true, true, false, true]);
+    /// ```
+    pub fn shift_right(&mut self, n: usize, fill: bool) {
+        let n = n.min(self.len());
+        let len = self.len();
+
+        self.copy_within(..len - n, n);
+        self.slice_mut(..n).fill(fill);
+    }
+
+    /// Rotates the values in place so that the first `mid` values move to the end,
+    /// and the value at `mid` becomes the first one.
+    ///
+    /// Both sides of `mid` are reversed, then the whole BoolSliceMut, each with BoolSliceMut::reverse(),
+    /// so that up to 64 values are moved at a time without allocating.
+    /// Just like `slice::rotate_left()`, this panics if `mid` is greater than BoolSliceMut.len().
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, true, false, true, false];
+    ///
+    /// bv.rotate_left(2);
+    ///
+    /// assert_eq!(bv, boolvec![false, true, false, true, true]);
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len(), "mid is out of bounds");
+
+        self.slice_mut(..mid).reverse();
+        self.slice_mut(mid..).reverse();
+        self.reverse();
+    }
+
+    /// Rotates the values in place so that the last `k` values move to the start.
+    ///
+    /// Same as BoolSliceMut::rotate_left() with BoolSliceMut.len() minus `k`, so no memory is allocated either.
+    /// Just like `slice::rotate_right()`, this panics if `k` is greater than BoolSliceMut.len().
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, true, false, true, false];
+    ///
+    /// bv.rotate_right(2);
+    ///
+    /// assert_eq!(bv, boolvec![true, false, true, true, false]);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len(), "k is out of bounds");
+
+        self.rotate_left(self.len() - k);
+    }
+
+    /// Reverses the order of the values in place.
+    ///
+    /// Up to 64 values from each end are swapped at a time, reversing their bits with `u64::reverse_bits()`.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true, true, false, true, false];
+    ///
+    /// bv.reverse();
+    ///
+    /// assert_eq!(bv, boolvec![false, true, false, true, true]);
+    /// ```
+    pub fn reverse(&mut self) {
+        let mut start = 0;
+        let mut end = self.len();
+
+        while end - start >= 2 {
+            let width = ((end - start) / 2).min(64);
+
+            let front = self.get_bits(start..start + width);
+            let back = self.get_bits(end - width..end);
+
+            self.set_bits(start..start + width, back.reverse_bits() >> (64 - width));
+            self.set_bits(end - width..end, front.reverse_bits() >> (64 - width));
+
+            start += width;
+            end -= width;
+        }
+    }
+
+    /// Copies all the values of `src` into the BoolSliceMut, starting at `dst_offset`.
+    /// Values are copied a whole byte at a time, shifting them into place when `dst_offset`
+    /// is not a multiple of 8 or `src` doesn't start on a byte boundary.
+    ///
+    /// Panics if `src` doesn't fit, meaning that `dst_offset + src.len()` is greater than BoolSliceMut.len().
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false; 12];
+    /// let sprite = boolvec![true, true, false, true];
+    ///
+    /// bv.overwrite_from(3, &sprite);
+    ///
+    /// assert_eq!(bv, boolvec![false, false, false, true, true, false, true, false, false, false, false, false]);
+    ///
+    /// bv.overwrite_from(10, sprite.slice(2..));
+    ///
+    /// assert_eq!(bv.get(10), Some(false));
+    /// assert_eq!(bv.get(11), Some(true));
+    /// ```
+    pub fn overwrite_from<'b>(&mut self, dst_offset: usize, src: impl Into<BoolSlice<'b>>) {
+        let src = src.into();
+
+        let count = src.len();
+
+        assert!(
+            dst_offset
+                .checked_add(count)
+                .is_some_and(|end| end <= self.len()),
+            "source (len {count}) does not fit at offset {dst_offset} of BoolSlice (len {})",
+            self.len()
+        );
+
+        for offset in (0..count).step_by(8) {
+            let byte = src.read_byte(offset);
+
+            self.write_byte(dst_offset + offset, byte, (count - offset).min(8));
+        }
+    }
+
+    /// Treating the values as a big-endian binary number, the first value being the most significant bit,
+    /// adds one to it. Carries are propagated 64 values at a time.
+    ///
+    /// Returns true if the number overflowed, in which case every value wraps around to false.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![false, true, true];
+    ///
+    /// assert!(!bv.increment());
+    /// assert_eq!(bv, boolvec![true, false, false]);
+    ///
+    /// let mut bv = boolvec![true; 3];
+    ///
+    /// assert!(bv.increment());
+    /// assert_eq!(bv, boolvec![false; 3]);
+    /// ```
+    pub fn increment(&mut self) -> bool {
+        let mut end = self.len();
+
+        while end > 0 {
+            let start = end.saturating_sub(64);
+            let chunk = self.get_bits(start..end);
+
+            if chunk != u64::MAX >> (64 - (end - start)) {
+                self.set_bits(start..end, chunk + 1);
+                return false;
+            }
+
+            self.set_bits(start..end, 0);
+            end = start;
+        }
+
+        true
+    }
+
+    /// Writes `source` at the indexes where `mask` is `true`, leaving the other values untouched.
+    /// `source` is either a `bool` written at every selected index, or a BoolSlice or BoolVec
+    /// whose values are written in order, just like BoolSlice::expand() scatters them.
+    /// Values past the end of the shortest of `self` and `mask` are ignored.
+    ///
+    /// Values are written a whole byte at a time in a single pass, skipping the bytes of `mask` not selecting any value.
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut column = boolvec![true, false, false, true, true];
+    /// let matching_rows = boolvec![false, true, true, true, false];
+    ///
+    /// column.masked_set(&matching_rows, true);
+    /// assert_eq!(column, boolvec![true, true, true, true, true]);
+    ///
+    /// column.masked_set(&matching_rows, &boolvec![false, true, false]);
+    /// assert_eq!(column, boolvec![true, false, true, false, true]);
+    ///
+    /// // Values read with masked_get() are written back in place
+    /// let values = column.masked_get(&matching_rows);
+    /// column.masked_set(&matching_rows, &values);
+    /// assert_eq!(column, boolvec![true, false, true, false, true]);
+    /// ```
+    pub fn masked_set<'b, S: MaskedSource>(&mut self, mask: impl Into<BoolSlice<'b>>, source: S) {
+        let mask = mask.into();
+
+        let len = self.len().min(mask.len());
+        let mut written = 0;
+
+        for start in (0..len).step_by(8) {
+            let count = (len - start).min(8);
+            let selected = mask.read_byte(start) & (u8::MAX << (8 - count));
+
+            if selected == 0 {
+                continue;
+            }
+
+            let byte =
+                (self.slice.read_byte(start) & !selected) | source.scattered(written, selected);
+
+            self.write_byte(start, byte, count);
+            written += selected.count_ones() as usize;
+        }
+    }
+}
+
+/// Implements methods calling the ones of BoolSlice with the same name on `self.as_slice()`,
+/// or the ones of BoolSliceMut on `self.as_mut_slice()` when they borrow `self` mutably
+macro_rules! forward_methods {
+    ($(
+        $(#[$attr:meta])*
+        fn $name:ident $([$($generics:tt)*])? (&$($lifetime:lifetime)? self $(, $arg:ident: $ty:ty)*) -> $ret:ty;
+    )*) => {$(
+        #[doc = concat!("Same as BoolSlice::", stringify!($name), "(), check its docs for more infos")]
+        $(#[$attr])*
+        pub fn $name $(<$($generics)*>)? (&$($lifetime)? self $(, $arg: $ty)*) -> $ret {
+            self.as_slice().$name($($arg),*)
+        }
+    )*};
+    ($(
+        $(#[$attr:meta])*
+        fn $name:ident $([$($generics:tt)*])? (&mut self $(, $arg:ident: $ty:ty)*) $(-> $ret:ty)?;
+    )*) => {$(
+        #[doc = concat!("Same as BoolSliceMut::", stringify!($name), "(), check its docs for more infos")]
+        $(#[$attr])*
+        pub fn $name $(<$($generics)*>)? (&mut self $(, $arg: $ty)*) $(-> $ret)? {
+            self.as_mut_slice().$name($($arg),*)
+        }
+    )*};
+}
+
+/// Implements every method of BoolSlice reading values, except BoolSlice::len() and BoolSlice::is_empty(),
+/// inside the impl block of a type with an `as_slice()` method
+macro_rules! forward_read_methods {
+    () => {
+        $crate::slice::forward_methods! {
+            fn bit_order(&self) -> BitOrder;
+            fn get[I: BoolSliceIndex](&self, index: I) -> Option<I::Output<'_>>;
+            fn try_get(&self, int_index: usize) -> Result<bool, BoolVecError>;
+            fn get_many(&self, indexes: &[usize]) -> Option<Vec<bool>>;
+            fn iter(&self) -> BoolVecIter<'_>;
+            fn count_ones(&self) -> usize;
+            fn count_zeros(&self) -> usize;
+            fn select_nth_one(&self, n: usize) -> Option<usize>;
+            fn select_nth_zero(&self, n: usize) -> Option<usize>;
+            fn slice[R: RangeBounds<usize>](&self, range: R) -> BoolSlice<'_>;
+            fn split_at(&self, mid: usize) -> (BoolSlice<'_>, BoolSlice<'_>);
+            fn chunks(&self, chunk_size: usize) -> Chunks<'_>;
+            fn windows(&self, window_size: usize) -> Windows<'_>;
+            fn windows_u64(&self, window_size: usize) -> WindowsU64<'_>;
+            fn runs(&self) -> Runs<'_>;
+            fn bytes(&self) -> Bytes<'_>;
+            fn to_bytes_with_order(&self, order: BitOrder) -> Vec<u8>;
+            fn try_to_u64(&self) -> Result<u64, BoolVecError>;
+            fn try_to_u128(&self) -> Result<u128, BoolVecError>;
+            fn get_bits[R: RangeBounds<usize>](&self, range: R) -> u64;
+            #[cfg(feature = "num-bigint")]
+            fn to_biguint(&self) -> num_bigint::BigUint;
+            fn to_arrow_buffer(&self) -> Vec<u8>;
+            #[cfg(feature = "base64")]
+            fn to_base64(&self) -> String;
+            fn to_runs(&self) -> Vec<(bool, u32)>;
+            fn to_indices(&self) -> Vec<usize>;
+            #[cfg(feature = "roaring")]
+            fn to_roaring(&self) -> roaring::RoaringBitmap;
+            fn write_to[W: io::Write](&self, writer: W) -> io::Result<()>;
+            fn display_with['a](&'a self, options: FormatOptions<'a>) -> DisplayWith<'a>;
+            fn chunks_u64(&self) -> ChunksU64<'_>;
+            fn zip_with['b, F: FnMut(bool, bool) -> bool](&self, other: impl Into<BoolSlice<'b>>, f: F) -> BoolVec;
+            fn checked_add['b](&self, other: impl Into<BoolSlice<'b>>) -> Option<BoolVec>;
+            fn to_gray(&self) -> BoolVec;
+            fn compress['b](&self, mask: impl Into<BoolSlice<'b>>) -> BoolVec;
+            fn expand['b](&self, mask: impl Into<BoolSlice<'b>>) -> BoolVec;
+            fn masked_get['b](&self, mask: impl Into<BoolSlice<'b>>) -> BoolVec;
+            fn is_subset['b](&self, other: impl Into<BoolSlice<'b>>) -> bool;
+            fn is_superset['b](&self, other: impl Into<BoolSlice<'b>>) -> bool;
+            fn is_disjoint['b](&self, other: impl Into<BoolSlice<'b>>) -> bool;
+            fn and_count['b](&self, other: impl Into<BoolSlice<'b>>) -> usize;
+            fn or_count['b](&self, other: impl Into<BoolSlice<'b>>) -> usize;
+            fn xor_count['b](&self, other: impl Into<BoolSlice<'b>>) -> usize;
+            fn jaccard_similarity['b](&self, other: impl Into<BoolSlice<'b>>) -> f64;
+            fn dice_coefficient['b](&self, other: impl Into<BoolSlice<'b>>) -> f64;
+            fn cosine_similarity['b](&self, other: impl Into<BoolSlice<'b>>) -> f64;
+            fn differences['a](&'a self, other: impl Into<BoolSlice<'a>>) -> Differences<'a>;
+            fn starts_with[S: BoolSequence + ?Sized](&self, needle: &S) -> bool;
+            fn ends_with[S: BoolSequence + ?Sized](&self, needle: &S) -> bool;
+            fn to_vec(&self) -> Vec<bool>;
+            #[cfg(feature = "rayon")]
+            fn par_iter(&self) -> crate::par::ParIter<'_>;
+            #[cfg(feature = "rayon")]
+            fn par_iter_ones(&self) -> crate::par::ParIterOnes<'_>;
+        }
+
+        /// Same as BoolSlice::get_unchecked(), check its docs for more infos
+        ///
+        /// # Safety
+        /// Calling this method with an index overflowing the length is undefined behavior,
+        /// even if the value is not used
+        pub unsafe fn get_unchecked(&self, int_index: usize) -> bool {
+            // SAFETY: the requirements are forwarded to the caller
+            unsafe { self.as_slice().get_unchecked(int_index) }
+        }
+    };
+}
+
+// Only ArchivedBoolVec uses them outside of this module
+#[cfg_attr(not(feature = "rkyv"), allow(unused_imports))]
+pub(crate) use {forward_methods, forward_read_methods};
+
+impl BoolSliceMut<'_> {
+    /// Returns the length of the BoolSliceMut
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 10];
+    ///
+    /// assert_eq!(bv.slice_mut(3..).len(), 7);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns true if the BoolSliceMut is empty
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let mut bv = boolvec![true; 10];
+    ///
+    /// assert!(bv.slice_mut(3..3).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    forward_read_methods!();
+}
+
+impl<A: Allocator> BoolVec<A> {
+    forward_read_methods!();
+
+    forward_methods! {
+        fn try_set(&mut self, int_index: usize, value: bool) -> Result<(), BoolVecError>;
+        fn try_negate(&mut self, int_index: usize) -> Result<bool, BoolVecError>;
+        fn set_many(&mut self, updates: &[(usize, bool)]) -> Option<()>;
+        fn set(&mut self, int_index: usize, value: bool) -> Option<()>;
+        fn negate(&mut self, int_index: usize) -> Option<bool>;
+        fn fill(&mut self, value: bool);
+        fn swap(&mut self, a: usize, b: usize) -> Option<()>;
+        fn set_bits[R: RangeBounds<usize>](&mut self, range: R, value: u64);
+        fn zip_with_in_place['b, F: FnMut(bool, bool) -> bool](&mut self, other: impl Into<BoolSlice<'b>>, f: F);
+        fn copy_within[R: RangeBounds<usize>](&mut self, src: R, dest: usize);
+        fn shift_left(&mut self, n: usize, fill: bool);
+        fn shift_right(&mut self, n: usize, fill: bool);
+        fn rotate_left(&mut self, mid: usize);
+        fn rotate_right(&mut self, k: usize);
+        fn reverse(&mut self);
+        fn overwrite_from['b](&mut self, dst_offset: usize, src: impl Into<BoolSlice<'b>>);
+        fn increment(&mut self) -> bool;
+        fn masked_set['b, S: MaskedSource](&mut self, mask: impl Into<BoolSlice<'b>>, source: S);
+    }
+
+    /// Same as BoolSliceMut::set_unchecked(), check its docs for more infos
+    ///
+    /// # Safety
+    /// Calling this method with an index overflowing BoolVec.len() is undefined behavior
+    pub unsafe fn set_unchecked(&mut self, int_index: usize, value: bool) {
+        // SAFETY: the requirements are forwarded to the caller
+        unsafe { self.as_mut_slice().set_unchecked(int_index, value) }
+    }
+
+    /// Same as BoolSliceMut::get_mut(), check its docs for more infos
+    pub fn get_mut(&mut self, int_index: usize) -> Option<BitMut<'_>> {
+        let value = self.get(int_index)?;
+
+        Some(BitMut::new(self.as_mut_slice(), int_index, value))
+    }
+
+    /// Same as BoolSliceMut::slice_mut(), check its docs for more infos
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> BoolSliceMut<'_> {
+        let (start, end) = range_to_indexes(range, self.len());
+
+        self.as_mut_slice().into_subslice(start, end)
+    }
+
+    /// Same as BoolSliceMut::split_at_mut(), check its docs for more infos
+    pub fn split_at_mut(&mut self, mid: usize) -> (BoolSliceMut<'_>, BoolSliceMut<'_>) {
+        self.as_mut_slice().into_split_at(mid)
+    }
+}
+
+impl<'a, A: Allocator> From<&'a BoolVec<A>> for BoolSlice<'a> {
+    fn from(bool_vec: &'a BoolVec<A>) -> Self {
+        bool_vec.as_slice()
+    }
+}
+
+impl<'a> From<&BoolSlice<'a>> for BoolSlice<'a> {
+    fn from(slice: &BoolSlice<'a>) -> Self {
+        *slice
+    }
+}
+
+impl<'a> From<&'a BoolSliceMut<'_>> for BoolSlice<'a> {
+    fn from(slice: &'a BoolSliceMut<'_>) -> Self {
+        slice.as_slice()
+    }
+}
+
+impl PartialEq for BoolSlice<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        (0..self.len())
+            .step_by(8)
+            .all(|start| self.read_byte(start) == other.read_byte(start))
+    }
+}
+
+impl Eq for BoolSlice<'_> {}
+
+impl PartialOrd for BoolSlice<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BoolSlice<'_> {
+    /// Compares two BoolSlices lexicographically, just like BoolVecs are compared.
+    /// Values are compared 64 at a time, stopping at the first chunk that differs
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true];
+    ///
+    /// assert!(bv.slice(1..) < bv.slice(..2));
+    /// assert!(bv.slice(..2) < bv.slice(..3));
+    /// ```
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let len = self.len().min(other.len());
+
+        for start in (0..len).step_by(64) {
+            let chunk_len = (len - start).min(64);
+
+            let a = self.read_u64(start, chunk_len);
+            let b = other.read_u64(start, chunk_len);
+
+            if a != b {
+                return a.cmp(&b);
+            }
+        }
+
+        self.len().cmp(&other.len())
+    }
+}
+
+impl Hash for BoolSlice<'_> {
+    /// Hashes the length of the BoolSlice, then its values 64 at a time.
+    /// The hash is the same as the one of a BoolVec holding the same values
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+
+        for chunk in self.chunks_u64() {
+            state.write_u64(chunk);
+        }
+    }
+}
+
+impl<A: Allocator> PartialEq<BoolVec<A>> for BoolSlice<'_> {
+    fn eq(&self, other: &BoolVec<A>) -> bool {
+        *self == other.as_slice()
+    }
+}
+
+impl<A: Allocator> PartialEq<BoolVec<A>> for BoolSliceMut<'_> {
+    fn eq(&self, other: &BoolVec<A>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+/// Implements PartialEq between BoolSlice-like types and sequences of bools, in both directions
+macro_rules! impl_bool_eq {
+    ($([$($generics:tt)*] $lhs:ty, $rhs:ty;)*) => {$(
+        impl<$($generics)*> PartialEq<$rhs> for $lhs {
+            fn eq(&self, other: &$rhs) -> bool {
+                other.matches(BoolSlice::from(self))
+            }
+        }
+
+        impl<$($generics)*> PartialEq<$lhs> for $rhs {
+            fn eq(&self, other: &$lhs) -> bool {
+                other == self
+            }
+        }
+    )*};
+}
+
+impl_bool_eq! {
+    [] BoolSlice<'_>, [bool];
+    [] BoolSlice<'_>, &[bool];
+    [] BoolSlice<'_>, Vec<bool>;
+    [const N: usize] BoolSlice<'_>, [bool; N];
+    [A: Allocator] BoolVec<A>, [bool];
+    [A: Allocator] BoolVec<A>, &[bool];
+    [A: Allocator] BoolVec<A>, Vec<bool>;
+    [A: Allocator, const N: usize] BoolVec<A>, [bool; N];
+}
+
+impl Debug for BoolSlice<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return f.write_str("[]");
+        }
+
+        if !f.alternate() {
+            return f.debug_list().entries(self).finish();
+        }
+
+        f.write_str("[\n")?;
+
+        let mut counter = 0;
+
+        for b in self {
+            if counter % 8 == 0 {
+                f.write_str("    ")?;
+            }
+
+            f.write_str(b.to_string().as_str())?;
+
+            if b {
+                f.write_char(' ')?;
+            }
+
+            counter += 1;
+            if counter < self.len() {
+                f.write_str(", ")?;
+            }
+
+            if counter % 8 == 0 {
+                f.write_char('\n')?;
+            }
+        }
+
+        f.write_str("\n]")
+    }
+}
+
+impl Display for BoolSlice<'_> {
+    /// Prints the values as a string of ones and zeros, one character per value.
+    /// Pretty printing separates every group of 8 values with an underscore,
+    /// and padding is applied to the whole string
+    /// ```rust
+    /// use bool_vec::boolvec;
+    ///
+    /// let bv = boolvec![true, false, true, true, false, false, true, false, true];
+    ///
+    /// assert_eq!(format!("{bv}"), "101100101");
+    /// assert_eq!(format!("{bv:#}"), "10110010_1");
+    /// assert_eq!(format!("{:>6}", bv.slice(..3)), "   101");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            FormatOptions::default().fmt_slice(*self, f)
+        } else {
+            FormatOptions::UNGROUPED.fmt_slice(*self, f)
+        }
+    }
+}
+
+/// Implements a radix formatting trait for BoolSlice, printing the bytes
+/// the values would have in a BoolVec with the same trait
+macro_rules! impl_radix_fmt {
+    ($($trait:ident),*) => {
+        $(
+            impl $trait for BoolSlice<'_> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    if self.is_empty() {
+                        return f.write_str("[]");
+                    }
+
+                    let order = self.bit_order();
+                    let bytes = (0..bytes_for(self.len()))
+                        .map(|byte_index| order.convert(self.read_byte(byte_index * 8)));
+
+                    fmt_bytes(bytes, f, $trait::fmt)
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_fmt!(Binary, Octal, LowerHex, UpperHex);
+
+/// Implements a formatting trait for BoolSliceMut, printing the values just like a BoolSlice does
+macro_rules! impl_mut_fmt {
+    ($($trait:ident),*) => {
+        $(
+            impl $trait for BoolSliceMut<'_> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    $trait::fmt(&self.as_slice(), f)
+                }
+            }
+        )*
+    };
+}
+
+impl_mut_fmt!(Debug, Display, Binary, Octal, LowerHex, UpperHex);
+
+impl<'a> IntoIterator for BoolSlice<'a> {
+    type Item = bool;
+
+    type IntoIter = BoolVecIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &BoolSlice<'a> {
+    type Item = bool;
+
+    type IntoIter = BoolVecIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a BoolSliceMut<'_> {
+    type Item = bool;
+
+    type IntoIter = BoolVecIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
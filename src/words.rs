@@ -0,0 +1,219 @@
+//! Storage for the words of a BoolVec, keeping short BoolVecs inline instead of allocating.
+
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+
+use allocator_api2::alloc::{Allocator, Global};
+use allocator_api2::collections::TryReserveError;
+use allocator_api2::vec::Vec;
+
+/// Number of words stored inline, before spilling to the heap
+pub(crate) const INLINE_WORDS: usize = 2;
+
+/// The words of a BoolVec, either stored inline or in a vector allocated with `A`.
+///
+/// Inline storage always holds INLINE_WORDS words, even when fewer are needed.
+/// This is fine since words past the end of a BoolVec are always zero.
+/// The allocator is kept next to the inline words, so that they can be moved to the heap later
+#[derive(Clone)]
+pub(crate) enum Words<A: Allocator> {
+    Inline([u64; INLINE_WORDS], A),
+    Heap(Vec<u64, A>),
+}
+
+impl<A: Allocator> Words<A> {
+    /// Returns empty inline storage, which will allocate with `alloc` once it spills to the heap
+    pub(crate) fn new_in(alloc: A) -> Self {
+        Self::Inline([0; INLINE_WORDS], alloc)
+    }
+
+    /// Returns storage holding `len` zeroed words, with a capacity of exactly `len`
+    /// if they don't fit inline
+    pub(crate) fn zeroed_in(len: usize, alloc: A) -> Self {
+        if len <= INLINE_WORDS {
+            return Self::new_in(alloc);
+        }
+
+        Self::Heap(allocator_api2::vec![in alloc; 0; len])
+    }
+
+    /// Returns true if the words are stored inline
+    pub(crate) fn is_inline(&self) -> bool {
+        matches!(self, Self::Inline(..))
+    }
+
+    /// Returns how many words can be held without reallocating
+    pub(crate) fn capacity(&self) -> usize {
+        match self {
+            Self::Inline(words, _) => words.len(),
+            Self::Heap(vec) => vec.capacity(),
+        }
+    }
+
+    /// Returns the allocator used when the words are moved to the heap
+    pub(crate) fn allocator(&self) -> &A {
+        match self {
+            Self::Inline(_, alloc) => alloc,
+            Self::Heap(vec) => vec.allocator(),
+        }
+    }
+
+    /// Replaces the storage with the one returned by `f`, which is given the current one by value.
+    ///
+    /// # Safety
+    /// `f` must not panic, otherwise the current storage would be dropped twice
+    unsafe fn replace_with<F: FnOnce(Self) -> Self>(&mut self, f: F) {
+        // SAFETY: the storage is read exactly once and overwritten right after,
+        // and the caller guarantees that nothing in between unwinds
+        unsafe { ptr::write(self, f(ptr::read(self))) }
+    }
+
+    /// Returns the vector holding the words, moving them to the heap if they're stored inline
+    fn heap(&mut self) -> &mut Vec<u64, A> {
+        if let Self::Inline(words, _) = *self {
+            // SAFETY: moving the allocator into an empty vector doesn't allocate, so it can't panic
+            unsafe {
+                self.replace_with(|storage| match storage {
+                    Self::Inline(_, alloc) => Self::Heap(Vec::new_in(alloc)),
+                    heap => heap,
+                });
+            }
+
+            if let Self::Heap(vec) = self {
+                vec.extend_from_slice(&words);
+            }
+        }
+
+        match self {
+            Self::Heap(vec) => vec,
+            Self::Inline(..) => unreachable!(),
+        }
+    }
+
+    /// Same as `Vec::reserve()`, moving the words to the heap if they don't fit inline anymore
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        if additional > 0 {
+            self.heap().reserve(additional);
+        }
+    }
+
+    /// Same as `Vec::reserve_exact()`, moving the words to the heap if they don't fit inline anymore
+    pub(crate) fn reserve_exact(&mut self, additional: usize) {
+        if additional > 0 {
+            self.heap().reserve_exact(additional);
+        }
+    }
+
+    /// Same as `Vec::try_reserve()`, moving the words to the heap if they don't fit inline anymore
+    pub(crate) fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if additional == 0 {
+            return Ok(());
+        }
+
+        self.heap().try_reserve(additional)
+    }
+
+    /// Same as `Vec::try_reserve_exact()`, moving the words to the heap if they don't fit inline anymore
+    pub(crate) fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if additional == 0 {
+            return Ok(());
+        }
+
+        self.heap().try_reserve_exact(additional)
+    }
+
+    /// Appends zeroed words until `len` words are held
+    pub(crate) fn extend_zeroed(&mut self, len: usize) {
+        if len > self.len() {
+            self.heap().resize(len, 0);
+        }
+    }
+
+    /// Drops the words past `len`, which must all be zero, then shrinks the capacity
+    /// as much as possible while keeping it at least `min_capacity`.
+    /// Words are moved back inline if they fit
+    pub(crate) fn shrink(&mut self, len: usize, min_capacity: usize) {
+        let Self::Heap(vec) = self else {
+            return;
+        };
+
+        vec.truncate(len);
+
+        if vec.len() > INLINE_WORDS || min_capacity > INLINE_WORDS {
+            vec.shrink_to(min_capacity);
+            return;
+        }
+
+        let mut words = [0; INLINE_WORDS];
+        words[..vec.len()].copy_from_slice(vec);
+
+        let mut buffer = None;
+
+        // SAFETY: taking the vector apart doesn't free its buffer, so it can't panic
+        unsafe {
+            self.replace_with(|storage| match storage {
+                Self::Heap(vec) => {
+                    let (ptr, _, capacity, alloc) = vec.into_raw_parts_with_alloc();
+                    buffer = Some((ptr, capacity));
+
+                    Self::Inline(words, alloc)
+                }
+                inline => inline,
+            });
+        }
+
+        // The buffer is only freed once the storage is valid again, in case the allocator panics
+        if let (Some((ptr, capacity)), Self::Inline(_, alloc)) = (buffer, &*self) {
+            // SAFETY: the buffer was allocated by `alloc` with the given capacity,
+            // and none of its words need to be dropped
+            drop(unsafe { Vec::from_raw_parts_in(ptr, 0, capacity, alloc) });
+        }
+    }
+}
+
+impl Words<Global> {
+    /// Returns heap storage adopting the buffer of a regular vector, without copying its words
+    pub(crate) fn from_std_vec(vec: std::vec::Vec<u64>) -> Self {
+        let mut vec = ManuallyDrop::new(vec);
+
+        // SAFETY: both vectors allocate their buffer with the global allocator, using the same layout,
+        // and the original vector is never dropped
+        Self::Heap(unsafe {
+            Vec::from_raw_parts_in(vec.as_mut_ptr(), vec.len(), vec.capacity(), Global)
+        })
+    }
+
+    /// Moves the words into a regular vector, only allocating if they're stored inline
+    pub(crate) fn into_std_vec(self) -> std::vec::Vec<u64> {
+        match self {
+            Self::Inline(words, _) => words.to_vec(),
+            Self::Heap(vec) => {
+                let (ptr, len, capacity, _) = vec.into_raw_parts_with_alloc();
+
+                // SAFETY: both vectors allocate their buffer with the global allocator, using the same layout
+                unsafe { std::vec::Vec::from_raw_parts(ptr, len, capacity) }
+            }
+        }
+    }
+}
+
+impl<A: Allocator> Deref for Words<A> {
+    type Target = [u64];
+
+    fn deref(&self) -> &[u64] {
+        match self {
+            Self::Inline(words, _) => words,
+            Self::Heap(vec) => vec,
+        }
+    }
+}
+
+impl<A: Allocator> DerefMut for Words<A> {
+    fn deref_mut(&mut self) -> &mut [u64] {
+        match self {
+            Self::Inline(words, _) => words,
+            Self::Heap(vec) => vec,
+        }
+    }
+}